@@ -1,15 +1,44 @@
 //! Generate trait implementations from parsed struct data.
-use crate::parse::ParsedStruct;
+use crate::parse::{HandleField, HandleKind, ParsedStruct};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 /// Generate a sync `FromServices` implementation.
+///
+/// Generates `FromServicesWith` instead if the struct has `#[di(arg)]` fields.
 pub(crate) fn generate_sync(parsed: &ParsedStruct) -> TokenStream {
     let name = &parsed.name;
     let service_fields = &parsed.service_fields;
     let default_fields = &parsed.default_fields;
     let trait_field_names = parsed.trait_fields.iter().map(|f| &f.name);
     let trait_field_types = parsed.trait_fields.iter().map(|f| &f.trait_type);
+    let handle_fields = parsed.handle_fields.iter().map(generate_handle_field);
+    let arg_field_names = parsed.arg_fields.iter().map(|f| &f.name);
+    let body = quote! {
+        Ok(Self {
+            #(#service_fields: services.get()?,)*
+            #(#trait_field_names: services.get_trait::<#trait_field_types>()?,)*
+            #(#handle_fields,)*
+            #(#arg_field_names,)*
+            #(#default_fields: Default::default(),)*
+        })
+    };
+    if !parsed.arg_fields.is_empty() {
+        let (args_pattern, args_type) = generate_args(parsed);
+        return quote! {
+            impl ::studiole_di::prelude::FromServicesWith<#args_type> for #name {
+                type Error = ::studiole_di::prelude::ResolveError;
+
+                fn from_services_with(
+                    services: &::studiole_di::prelude::ServiceProvider,
+                    args: #args_type,
+                ) -> ::std::result::Result<Self, ::studiole_report::prelude::Report<::studiole_di::prelude::ResolveError>> {
+                    let #args_pattern = args;
+                    #body
+                }
+            }
+        };
+    }
     quote! {
         impl ::studiole_di::prelude::FromServices for #name {
             type Error = ::studiole_di::prelude::ResolveError;
@@ -17,23 +46,48 @@ pub(crate) fn generate_sync(parsed: &ParsedStruct) -> TokenStream {
             fn from_services(
                 services: &::studiole_di::prelude::ServiceProvider,
             ) -> ::std::result::Result<Self, ::studiole_report::prelude::Report<::studiole_di::prelude::ResolveError>> {
-                Ok(Self {
-                    #(#service_fields: services.get()?,)*
-                    #(#trait_field_names: services.get_trait::<#trait_field_types>()?,)*
-                    #(#default_fields: Default::default(),)*
-                })
+                #body
             }
         }
     }
 }
 
 /// Generate an async `FromServicesAsync` implementation.
+///
+/// Generates `FromServicesWithAsync` instead if the struct has `#[di(arg)]` fields.
 pub(crate) fn generate_async(parsed: &ParsedStruct) -> TokenStream {
     let name = &parsed.name;
     let service_fields = &parsed.service_fields;
     let default_fields = &parsed.default_fields;
     let trait_field_names = parsed.trait_fields.iter().map(|f| &f.name);
     let trait_field_types = parsed.trait_fields.iter().map(|f| &f.trait_type);
+    let handle_fields = parsed.handle_fields.iter().map(generate_handle_field);
+    let arg_field_names = parsed.arg_fields.iter().map(|f| &f.name);
+    let body = quote! {
+        Ok(Self {
+            #(#service_fields: services.get_async().await?,)*
+            #(#trait_field_names: services.get_trait_async::<#trait_field_types>().await?,)*
+            #(#handle_fields,)*
+            #(#arg_field_names,)*
+            #(#default_fields: Default::default(),)*
+        })
+    };
+    if !parsed.arg_fields.is_empty() {
+        let (args_pattern, args_type) = generate_args(parsed);
+        return quote! {
+            impl ::studiole_di::prelude::FromServicesWithAsync<#args_type> for #name {
+                type Error = ::studiole_di::prelude::ResolveError;
+
+                async fn from_services_with_async(
+                    services: &::studiole_di::prelude::ServiceProvider,
+                    args: #args_type,
+                ) -> ::std::result::Result<Self, ::studiole_report::prelude::Report<::studiole_di::prelude::ResolveError>> {
+                    let #args_pattern = args;
+                    #body
+                }
+            }
+        };
+    }
     quote! {
         impl ::studiole_di::prelude::FromServicesAsync for #name {
             type Error = ::studiole_di::prelude::ResolveError;
@@ -41,12 +95,38 @@ pub(crate) fn generate_async(parsed: &ParsedStruct) -> TokenStream {
             async fn from_services_async(
                 services: &::studiole_di::prelude::ServiceProvider,
             ) -> ::std::result::Result<Self, ::studiole_report::prelude::Report<::studiole_di::prelude::ResolveError>> {
-                Ok(Self {
-                    #(#service_fields: services.get_async().await?,)*
-                    #(#trait_field_names: services.get_trait_async::<#trait_field_types>().await?,)*
-                    #(#default_fields: Default::default(),)*
-                })
+                #body
             }
         }
     }
 }
+
+/// Generate the destructuring pattern and tuple type of the `#[di(arg)]` fields.
+fn generate_args(parsed: &ParsedStruct) -> (TokenStream, TokenStream) {
+    let names = parsed.arg_fields.iter().map(|f| &f.name);
+    let types = parsed.arg_fields.iter().map(|f| &f.ty);
+    (quote! { (#(#names,)*) }, quote! { (#(#types,)*) })
+}
+
+/// Generate the initializer of a handle field.
+///
+/// Creating a handle is sync so the same initializer is used by both derives.
+fn generate_handle_field(field: &HandleField) -> TokenStream {
+    let name = &field.name;
+    let (base, suffix) = match field.kind {
+        HandleKind::Lazy => ("lazy", ""),
+        HandleKind::AsyncLazy => ("lazy", "_async"),
+        HandleKind::Factory => ("factory", ""),
+        HandleKind::FactoryAsync => ("factory", "_async"),
+    };
+    match &field.trait_type {
+        None => {
+            let method = format_ident!("get_{base}{suffix}");
+            quote! { #name: services.#method()? }
+        }
+        Some(trait_type) => {
+            let method = format_ident!("get_{base}_trait{suffix}");
+            quote! { #name: services.#method::<#trait_type>()? }
+        }
+    }
+}
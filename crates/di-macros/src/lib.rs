@@ -10,6 +10,14 @@ use syn::parse_macro_input;
 /// Generates a sync implementation that resolves each field
 /// from the [`ServiceProvider`].
 ///
+/// - `Arc<dyn Trait>` fields are resolved as trait objects
+/// - `Lazy<T>`, `Lazy<dyn Trait>` and `AsyncLazy` fields are resolved on first use
+/// - `Factory<T>`, `Factory<dyn Trait>` and `FactoryAsync` fields create transients
+///   on demand
+/// - `#[di(default)]` fields are set to [`Default::default()`]
+/// - `#[di(arg)]` fields are taken from an argument tuple, in declaration order, by
+///   implementing `FromServicesWith<(..)>` instead of `FromServices`
+///
 /// # Example
 ///
 /// ```ignore
@@ -32,6 +40,9 @@ pub fn derive_from_services(input: TokenStream) -> TokenStream {
 /// Generates an async implementation that resolves each field
 /// from the [`ServiceProvider`].
 ///
+/// Fields are handled as by [`FromServices`](derive@FromServices). With `#[di(arg)]`
+/// fields `FromServicesWithAsync<(..)>` is implemented instead.
+///
 /// # Example
 ///
 /// ```ignore
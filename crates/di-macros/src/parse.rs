@@ -10,10 +10,32 @@ pub(crate) struct ParsedStruct {
     pub service_fields: Vec<syn::Ident>,
     /// Fields resolved as trait objects from the [`ServiceProvider`].
     pub trait_fields: Vec<TraitField>,
+    /// Fields holding a `Lazy`, `AsyncLazy`, `Factory` or `FactoryAsync` handle.
+    pub handle_fields: Vec<HandleField>,
+    /// Fields taken from the argument tuple, in declaration order.
+    pub arg_fields: Vec<ArgField>,
     /// Fields resolved via [`Default::default()`].
     pub default_fields: Vec<syn::Ident>,
 }
 
+/// Field taken from the argument tuple of `FromServicesWith`.
+#[derive(Debug)]
+pub(crate) struct ArgField {
+    /// Field name.
+    pub name: syn::Ident,
+    /// Field type.
+    pub ty: syn::Type,
+}
+
+/// Attribute controlling how a field is resolved.
+#[derive(Debug, Eq, PartialEq)]
+enum DiAttribute {
+    /// `#[di(default)]`
+    Default,
+    /// `#[di(arg)]`
+    Arg,
+}
+
 /// Field resolved as a trait object from the [`ServiceProvider`].
 #[derive(Debug)]
 pub(crate) struct TraitField {
@@ -23,6 +45,40 @@ pub(crate) struct TraitField {
     pub trait_type: syn::Type,
 }
 
+/// Field holding a handle that resolves a service on demand.
+#[derive(Debug)]
+pub(crate) struct HandleField {
+    /// Field name.
+    pub name: syn::Ident,
+    /// Kind of handle.
+    pub kind: HandleKind,
+    /// Inner trait type if the handle resolves a trait object, e.g. `dyn HttpFetch`.
+    pub trait_type: Option<syn::Type>,
+}
+
+/// Kind of handle held by a [`HandleField`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum HandleKind {
+    /// `Lazy<T>`, resolved on first use.
+    Lazy,
+    /// `AsyncLazy<T>`, resolved asynchronously on first use.
+    AsyncLazy,
+    /// `Factory<T>`, creating a transient on every call.
+    Factory,
+    /// `FactoryAsync<T>`, creating a transient asynchronously on every call.
+    FactoryAsync,
+}
+
+impl HandleKind {
+    /// Every kind with the name of its type.
+    const ALL: [(HandleKind, &str); 4] = [
+        (HandleKind::Lazy, "Lazy"),
+        (HandleKind::AsyncLazy, "AsyncLazy"),
+        (HandleKind::Factory, "Factory"),
+        (HandleKind::FactoryAsync, "FactoryAsync"),
+    ];
+}
+
 /// Parse a [`DeriveInput`] into a [`ParsedStruct`].
 pub(crate) fn parse_struct(input: &DeriveInput) -> Result<ParsedStruct, syn::Error> {
     if !input.generics.params.is_empty() {
@@ -37,17 +93,26 @@ pub(crate) fn parse_struct(input: &DeriveInput) -> Result<ParsedStruct, syn::Err
             "FromServices derive only supports structs",
         ));
     };
-    let (service_fields, trait_fields, default_fields) = match &data.fields {
-        Fields::Unit => (Vec::new(), Vec::new(), Vec::new()),
+    let mut service_fields = Vec::new();
+    let mut trait_fields = Vec::new();
+    let mut handle_fields = Vec::new();
+    let mut arg_fields = Vec::new();
+    let mut default_fields = Vec::new();
+    match &data.fields {
+        Fields::Unit => {}
         Fields::Named(fields) => {
-            let mut service_fields = Vec::new();
-            let mut trait_fields = Vec::new();
-            let mut default_fields = Vec::new();
             for field in &fields.named {
                 let ident = field.ident.clone().expect("named field should have ident");
-                let is_default = has_di_default(&field.attrs)?;
-                if is_default {
+                let attribute = parse_di_attribute(&field.attrs)?;
+                if attribute == Some(DiAttribute::Default) {
                     default_fields.push(ident);
+                } else if attribute == Some(DiAttribute::Arg) {
+                    arg_fields.push(ArgField {
+                        name: ident,
+                        ty: field.ty.clone(),
+                    });
+                } else if let Some(handle_field) = extract_handle(&field.ty, &ident) {
+                    handle_fields.push(handle_field);
                 } else if let Some(trait_type) = extract_arc_dyn_type(&field.ty) {
                     trait_fields.push(TraitField {
                         name: ident,
@@ -57,7 +122,6 @@ pub(crate) fn parse_struct(input: &DeriveInput) -> Result<ParsedStruct, syn::Err
                     service_fields.push(ident);
                 }
             }
-            (service_fields, trait_fields, default_fields)
         }
         other @ Fields::Unnamed(_) => {
             return Err(syn::Error::new(
@@ -65,22 +129,42 @@ pub(crate) fn parse_struct(input: &DeriveInput) -> Result<ParsedStruct, syn::Err
                 "FromServices derive only supports structs with named fields",
             ));
         }
-    };
+    }
     Ok(ParsedStruct {
         name: input.ident.clone(),
         service_fields,
         trait_fields,
+        handle_fields,
+        arg_fields,
         default_fields,
     })
 }
 
 /// Extract the inner trait type if the field type is `Arc<dyn Trait>`.
 fn extract_arc_dyn_type(ty: &syn::Type) -> Option<syn::Type> {
+    let inner = extract_type_argument(ty, "Arc")?;
+    matches!(inner, syn::Type::TraitObject(_)).then(|| inner.clone())
+}
+
+/// Extract a [`HandleField`] if the field type is one of the [`HandleKind`] types.
+fn extract_handle(ty: &syn::Type, ident: &syn::Ident) -> Option<HandleField> {
+    let (kind, inner) = HandleKind::ALL
+        .into_iter()
+        .find_map(|(kind, wrapper)| Some((kind, extract_type_argument(ty, wrapper)?)))?;
+    Some(HandleField {
+        name: ident.clone(),
+        kind,
+        trait_type: matches!(inner, syn::Type::TraitObject(_)).then(|| inner.clone()),
+    })
+}
+
+/// Extract the first type argument if the field type is `wrapper<T>`.
+fn extract_type_argument<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
     let syn::Type::Path(type_path) = ty else {
         return None;
     };
     let segment = type_path.path.segments.last()?;
-    if segment.ident != "Arc" {
+    if segment.ident != wrapper {
         return None;
     }
     let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
@@ -89,22 +173,28 @@ fn extract_arc_dyn_type(ty: &syn::Type) -> Option<syn::Type> {
     let syn::GenericArgument::Type(inner) = args.args.first()? else {
         return None;
     };
-    matches!(inner, syn::Type::TraitObject(_)).then(|| inner.clone())
+    Some(inner)
 }
 
-/// Check if a field has the `#[di(default)]` attribute.
-fn has_di_default(attrs: &[syn::Attribute]) -> Result<bool, syn::Error> {
+/// Parse the `#[di(default)]` or `#[di(arg)]` attribute of a field.
+fn parse_di_attribute(attrs: &[syn::Attribute]) -> Result<Option<DiAttribute>, syn::Error> {
     for attr in attrs {
         if !attr.path().is_ident("di") {
             continue;
         }
+        let mut attribute = None;
         attr.parse_nested_meta(|meta| {
             if meta.path.is_ident("default") {
+                attribute = Some(DiAttribute::Default);
+                return Ok(());
+            }
+            if meta.path.is_ident("arg") {
+                attribute = Some(DiAttribute::Arg);
                 return Ok(());
             }
             Err(meta.error("unknown di attribute"))
         })?;
-        return Ok(true);
+        return Ok(attribute);
     }
-    Ok(false)
+    Ok(None)
 }
@@ -230,3 +230,97 @@ fn async_mixed_concrete_and_trait_fields() {
     let output = generate::generate_async(&parsed);
     insta::assert_snapshot!(format_tokens(output));
 }
+
+#[test]
+fn sync_lazy_fields() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Handler {
+            config: Lazy<Config>,
+            cache: Lazy<dyn Get>,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_sync(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
+
+#[test]
+fn async_lazy_fields() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Handler {
+            config: Arc<Config>,
+            db: AsyncLazy<AsyncDatabase>,
+            cache: AsyncLazy<dyn Get>,
+            reports: Lazy<Reports>,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_async(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
+
+#[test]
+fn sync_factory_fields() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Worker {
+            connections: Factory<Connection>,
+            requests: Factory<dyn Request>,
+            #[di(default)]
+            retries: u16,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_sync(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
+
+#[test]
+fn async_factory_fields() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Worker {
+            connections: FactoryAsync<Connection>,
+            requests: FactoryAsync<dyn Request>,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_async(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
+
+#[test]
+fn sync_arg_fields() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Session {
+            config: Arc<Config>,
+            #[di(arg)]
+            user_id: u64,
+            #[di(arg)]
+            path: PathBuf,
+            #[di(default)]
+            retries: u16,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_sync(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
+
+#[test]
+fn async_arg_field() {
+    let input: DeriveInput = syn::parse2(quote! {
+        pub struct Session {
+            db: Arc<AsyncDatabase>,
+            #[di(arg)]
+            user_id: u64,
+        }
+    })
+    .expect("input should parse");
+    let parsed = parse_struct(&input).expect("struct should parse");
+    let output = generate::generate_async(&parsed);
+    insta::assert_snapshot!(format_tokens(output));
+}
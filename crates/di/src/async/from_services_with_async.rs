@@ -0,0 +1,19 @@
+//! Async service construction trait with runtime arguments.
+use crate::prelude::*;
+
+/// Create an instance asynchronously from caller-supplied arguments and dependencies
+/// resolved from the [`ServiceProvider`].
+///
+/// `Args` is usually a tuple. Construct with [`ServiceProvider::get_with_async`].
+pub trait FromServicesWithAsync<Args>: Send + Sync + 'static {
+    /// Error type returned by [`FromServicesWithAsync::from_services_with_async`].
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Create an instance asynchronously from the arguments and the [`ServiceProvider`].
+    fn from_services_with_async(
+        services: &ServiceProvider,
+        args: Args,
+    ) -> impl Future<Output = Result<Self, Report<Self::Error>>> + Send
+    where
+        Self: Sized;
+}
@@ -0,0 +1,11 @@
+//! Async service initialization trait.
+use crate::prelude::*;
+
+/// Perform one-time async initialization after construction.
+pub trait InitAsync: Send + Sync + 'static {
+    /// Initialize the service asynchronously.
+    fn init_async(
+        &self,
+        services: &ServiceProvider,
+    ) -> impl Future<Output = Result<(), Report<InitError>>> + Send;
+}
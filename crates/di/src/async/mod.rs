@@ -1,5 +1,7 @@
 //! Async service registration and resolution.
 mod from_services_async;
+mod from_services_with_async;
+mod init_async;
 mod service_builder_async;
 #[cfg(feature = "traits")]
 mod service_builder_async_traits;
@@ -8,3 +10,5 @@ mod service_provider_async;
 mod service_provider_async_traits;
 
 pub use from_services_async::*;
+pub use from_services_with_async::*;
+pub use init_async::*;
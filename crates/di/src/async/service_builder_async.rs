@@ -5,22 +5,74 @@ impl ServiceBuilder {
     /// Register a singleton type for async resolution via [`FromServicesAsync`].
     #[must_use]
     pub fn with_type_async<T: FromServicesAsync>(self) -> Self {
-        self.register_type_async::<T>(Scope::Singleton)
+        self.register_type_async::<T>(SingletonLifetime)
     }
 
     /// Register a transient type for async resolution via [`FromServicesAsync`].
     #[must_use]
     pub fn with_type_async_transient<T: FromServicesAsync>(self) -> Self {
-        self.register_type_async::<T>(Scope::Transient)
+        self.register_type_async::<T>(TransientLifetime)
     }
 
-    /// Register an async type with the given scope.
-    pub(super) fn register_type_async<T: FromServicesAsync>(mut self, scope: Scope) -> Self {
+    /// Register a type for async resolution that is shared while in use and rebuilt
+    /// after release.
+    ///
+    /// See [`with_type_weak`](ServiceBuilder::with_type_weak).
+    #[must_use]
+    pub fn with_type_async_weak<T: FromServicesAsync>(self) -> Self {
+        self.register_type_async::<T>(WeakLifetime)
+    }
+
+    /// Register a type for async resolution that is cached once per thread.
+    ///
+    /// The instance is cached for the thread that completes its construction. See
+    /// [`with_type_thread_local`](ServiceBuilder::with_type_thread_local).
+    #[must_use]
+    pub fn with_type_async_thread_local<T: FromServicesAsync>(self) -> Self {
+        self.register_type_async::<T>(ThreadLocalLifetime)
+    }
+
+    /// Register a singleton type for async resolution that is constructed eagerly
+    /// by [`ServiceProvider::warm_up`].
+    #[must_use]
+    pub fn with_type_async_eager<T: FromServicesAsync>(self) -> Self {
+        self.register_type_async::<T>(SingletonLifetime)
+            .mark_eager(TypeId::of::<T>())
+    }
+
+    /// Mark a type for async initialization during [`ServiceProvider::init_async`].
+    ///
+    /// The service is resolved via [`get_async`](ServiceProvider::get_async) so it may be
+    /// registered with [`with_type_async`](ServiceBuilder::with_type_async).
+    #[must_use]
+    pub fn with_init_async<T: InitAsync>(mut self) -> Self {
+        let run: AsyncInitFn = Arc::new(|services: ServiceProvider| {
+            Box::pin(async move {
+                let instance = services
+                    .get_async_unguarded::<T>()
+                    .await
+                    .change_context(InitError::Init)?;
+                instance.init_async(&services).await
+            })
+        });
+        self.inits.push(InitStep {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            run: InitRun::Async(run),
+        });
+        self
+    }
+
+    /// Register an async type with the given lifetime.
+    pub(crate) fn register_type_async<T: FromServicesAsync>(
+        mut self,
+        lifetime: impl Lifetime,
+    ) -> Self {
         let type_id = TypeId::of::<T>();
-        let sync_factory: SyncFactory = Box::new(|_services: &ServiceProvider| {
+        let sync_factory: SyncFactory = Arc::new(|_services: &ServiceProvider| {
             Err(Report::new(ResolveError::Async)).attach("type", type_name::<T>())
         });
-        let async_factory: AsyncFactory = Box::new(|services: ServiceProvider| {
+        let async_factory: AsyncFactory = Arc::new(|services: ServiceProvider| {
             Box::pin(async move {
                 let instance = T::from_services_async(&services)
                     .await
@@ -29,11 +81,14 @@ impl ServiceBuilder {
                 Ok(instance.into_any())
             })
         });
-        self.factories.insert(
+        self.insert_factory(
             type_id,
             Registration {
-                scope,
+                type_name: type_name::<T>(),
+                lifetime: Arc::new(lifetime),
+                eager: false,
                 is_async: true,
+                profile: None,
                 factory: sync_factory,
                 async_factory: Some(async_factory),
             },
@@ -7,7 +7,7 @@ impl ServiceBuilder {
     /// - Automatically registers `Impl` via [`with_type_async`](ServiceBuilder::with_type_async)
     ///   if it has not already been registered
     /// - If `Impl` was already registered (by a prior `with_trait_async` or `with_type_async`
-    ///   call), the existing registration and its scope are kept unchanged
+    ///   call), the existing registration and its lifetime are kept unchanged
     ///
     /// # Example
     ///
@@ -22,7 +22,7 @@ impl ServiceBuilder {
     where
         Impl: FromServicesAsync + Unsize<Trait>,
     {
-        self.register_trait_async::<Trait, Impl>(Scope::Singleton)
+        self.register_trait_async::<Trait, Impl>(SingletonLifetime)
     }
 
     /// Register an async concrete type as transient and resolve it as a trait object.
@@ -30,46 +30,49 @@ impl ServiceBuilder {
     /// - Automatically registers `Impl` via [`with_type_async_transient`](ServiceBuilder::with_type_async_transient)
     ///   if it has not already been registered
     /// - If `Impl` was already registered (by a prior `with_trait_async_transient` or
-    ///   `with_type_async_transient` call), the existing registration and its scope are
+    ///   `with_type_async_transient` call), the existing registration and its lifetime are
     ///   kept unchanged
     #[must_use]
     pub fn with_trait_async_transient<Trait: ?Sized + Send + Sync + 'static, Impl>(self) -> Self
     where
         Impl: FromServicesAsync + Unsize<Trait>,
     {
-        self.register_trait_async::<Trait, Impl>(Scope::Transient)
+        self.register_trait_async::<Trait, Impl>(TransientLifetime)
     }
 
-    /// Register an async trait object with the given scope.
+    /// Register an async trait object with the given lifetime.
     #[expect(
         clippy::as_conversions,
         reason = "unsizing coercion to resolve concrete type as trait object"
     )]
     fn register_trait_async<Trait: ?Sized + Send + Sync + 'static, Impl>(
         mut self,
-        scope: Scope,
+        lifetime: impl Lifetime + Clone,
     ) -> Self
     where
         Impl: FromServicesAsync + Unsize<Trait>,
     {
         if !self.factories.contains_key(&TypeId::of::<Impl>()) {
-            self = self.register_type_async::<Impl>(scope);
+            self = self.register_type_async::<Impl>(lifetime.clone());
         }
         let type_id = TypeId::of::<Arc<Trait>>();
-        let sync_factory: SyncFactory = Box::new(|_services: &ServiceProvider| {
+        let sync_factory: SyncFactory = Arc::new(|_services: &ServiceProvider| {
             Err(Report::new(ResolveError::Async)).attach("type", type_name::<Arc<Trait>>())
         });
-        let async_factory: AsyncFactory = Box::new(|services: ServiceProvider| {
+        let async_factory: AsyncFactory = Arc::new(|services: ServiceProvider| {
             Box::pin(async move {
                 let instance = services.get_async::<Impl>().await? as Arc<Trait>;
                 Ok(instance.into_any())
             })
         });
-        self.factories.insert(
+        self.insert_factory(
             type_id,
             Registration {
-                scope,
+                type_name: type_name::<Arc<Trait>>(),
+                lifetime: Arc::new(lifetime),
+                eager: false,
                 is_async: true,
+                profile: None,
                 factory: sync_factory,
                 async_factory: Some(async_factory),
             },
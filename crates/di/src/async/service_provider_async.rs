@@ -9,17 +9,146 @@ impl ServiceProvider {
         let type_name = type_name::<T>();
         trace!(type_name, "Resolving service async");
         let type_id = TypeId::of::<T>();
+        self.check_lifecycle(type_id, type_name)?;
+        let dynamic = self.resolve_async(type_id, type_name).await?;
+        Ok(dynamic.expect_downcast::<T>())
+    }
+
+    /// Resolve a concrete type asynchronously without the strict lifecycle checks.
+    pub(crate) async fn get_async_unguarded<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<Arc<T>, Report<ResolveError>> {
+        let dynamic = self
+            .resolve_async(TypeId::of::<T>(), type_name::<T>())
+            .await?;
+        Ok(dynamic.expect_downcast::<T>())
+    }
+
+    /// Resolve a type-erased instance by type, supporting async registrations.
+    pub(crate) async fn resolve_async(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> FactoryResult {
         if let Some(dynamic) = self.get_cached(type_id) {
-            return Ok(dynamic.expect_downcast::<T>());
+            return Ok(dynamic);
+        }
+        let Some(registration) = self.get_registration(type_id, type_name)? else {
+            let parent = self.parent(type_name)?;
+            return Box::pin(parent.resolve_async(type_id, type_name)).await;
+        };
+        let context = LifetimeContext::new(self, type_id, registration);
+        if let Some(dynamic) = registration.lifetime.get(&context) {
+            return Ok(dynamic);
+        }
+        if registration.lifetime.describe() != ServiceLifetime::Singleton {
+            let dynamic = self.call_factory(registration).await?;
+            return Ok(registration.lifetime.store(&context, dynamic));
+        }
+        let lock = self.construction_lock(type_id);
+        let _constructing = lock.lock().await;
+        if let Some(dynamic) = registration.lifetime.get(&context) {
+            return Ok(dynamic);
         }
-        let registration = self.get_registration(type_id, type_name)?;
         let dynamic = self.call_factory(registration).await?;
-        self.cache_if_singleton(type_id, registration.scope, &dynamic);
-        Ok(dynamic.expect_downcast::<T>())
+        Ok(registration.lifetime.store(&context, dynamic))
+    }
+
+    /// Get the lock held while a singleton of this type is constructed.
+    ///
+    /// Concurrent resolutions wait for the first to finish rather than
+    /// constructing the singleton again.
+    fn construction_lock(&self, type_id: TypeId) -> Arc<AsyncMutex<()>> {
+        self.registry
+            .constructing
+            .lock()
+            .expect("should be able to lock constructing")
+            .entry(type_id)
+            .or_default()
+            .clone()
+    }
+
+    /// Construct every singleton marked as eager, including async registrations.
+    ///
+    /// - Eager registrations are constructed concurrently, each resolving its own
+    ///   dependencies first
+    /// - A singleton shared by several eager registrations is constructed once,
+    ///   with the others waiting for it
+    /// - Once cached, async singletons can also be resolved by the sync
+    ///   [`get`](ServiceProvider::get)
+    /// - Pools are filled to the minimum set by `PoolConfig::with_min`
+    pub async fn warm_up(&self) -> Result<(), Report<ResolveError>> {
+        let pending = self
+            .eager_registrations()
+            .map(|(type_id, registration)| self.resolve_async(type_id, registration.type_name));
+        for result in join_all(pending).await {
+            result?;
+        }
+        #[cfg(feature = "pool")]
+        self.fill_pools()?;
+        Ok(())
+    }
+
+    /// Run all registered init steps, supporting async steps.
+    ///
+    /// - Eager singletons, including async, are constructed first via
+    ///   [`warm_up`](ServiceProvider::warm_up)
+    /// - Init steps run concurrently unless ordered by
+    ///   [`with_init_after`](ServiceBuilder::with_init_after) or
+    ///   [`with_init_before`](ServiceBuilder::with_init_before)
+    /// - Failures are handled according to the [`InitMode`]
+    ///
+    /// - Returns [`InitError::AlreadyInitialized`] if the [`Lifecycle`] is not
+    ///   [`Built`](Lifecycle::Built)
+    /// - Returns [`InitError::Cycle`] if the ordering constraints form a cycle
+    /// - Returns [`InitError::Aggregate`] if any step failed in [`InitMode::Rollback`]
+    pub async fn init_async(self) -> Result<Self, Report<InitError>> {
+        self.begin_init()?;
+        let result = self.run_init_async().await;
+        self.finish_init(result).map(|()| self)
+    }
+
+    /// Sort and run the init steps, one wave at a time.
+    async fn run_init_async(&self) -> Result<(), Report<InitError>> {
+        let plan = sort_init_steps(&self.registry.inits, &self.registry.init_constraints)?;
+        self.warm_up().await.change_context(InitError::Init)?;
+        let steps = &self.registry.inits;
+        let mut progress = InitProgress::new(&plan);
+        for wave in &plan.waves {
+            let runnable: Vec<usize> = wave
+                .iter()
+                .copied()
+                .filter(|&index| progress.is_runnable(index))
+                .collect();
+            let pending = runnable
+                .iter()
+                .filter_map(|&index| steps.get(index))
+                .map(|step| self.run_init_step(step));
+            let results = join_all(pending).await;
+            for (index, result) in runnable.into_iter().zip(results) {
+                match result {
+                    Err(error) if self.registry.init_mode == InitMode::FailFast => {
+                        return Err(error);
+                    }
+                    result => progress.record(index, result),
+                }
+            }
+        }
+        progress.finish(self, steps)
+    }
+
+    /// Run a single init step, dispatching to async if needed.
+    async fn run_init_step(&self, step: &InitStep) -> Result<(), Report<InitError>> {
+        match &step.run {
+            InitRun::Sync(init_fn) => init_fn(self)?,
+            InitRun::Async(init_fn) => init_fn(self.clone()).await?,
+        }
+        self.mark_initialized(step.type_id);
+        Ok(())
     }
 
     /// Call the appropriate factory for a registration, dispatching to async if needed.
-    pub(super) async fn call_factory(
+    pub(crate) async fn call_factory(
         &self,
         registration: &Registration,
     ) -> Result<Arc<dyn Any + Send + Sync>, Report<ResolveError>> {
@@ -38,6 +167,30 @@ impl ServiceProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
+    use tokio::task::yield_now;
+    use tokio::time::{Instant as TokioInstant, sleep};
+
+    #[tokio::test]
+    async fn weak_async_rebuilds_after_release() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async_weak::<AsyncCountedService>()
+            .build();
+        let first = services.expect_async::<AsyncCountedService>().await;
+        let second = services.expect_async::<AsyncCountedService>().await;
+        let shared = Arc::ptr_eq(&first, &second);
+
+        // Act
+        drop(first);
+        drop(second);
+        let _third = services.expect_async::<AsyncCountedService>().await;
+
+        // Assert
+        assert!(shared);
+        assert_eq!(services.expect::<ConstructionCounter>().get(), 2);
+    }
 
     #[tokio::test]
     async fn get_async_resolves_sync_registration() {
@@ -154,4 +307,387 @@ mod tests {
         // Assert
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn warm_up_constructs_eager_async_singletons() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async_eager::<AsyncCountedService>()
+            .with_type_eager::<CountedService>()
+            .build();
+        // Act
+        services.warm_up().await.expect("should warm up");
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn warm_up_constructs_shared_dependency_once() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async::<SlowShared>()
+            .with_type_async_eager::<SharedUserA>()
+            .with_type_async_eager::<SharedUserB>()
+            .build();
+        // Act
+        services.warm_up().await.expect("should warm up");
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn warm_up_constructs_independent_singletons_concurrently() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_async_eager::<Sleepy<1>>()
+            .with_type_async_eager::<Sleepy<2>>()
+            .build();
+        let start = TokioInstant::now();
+        // Act
+        services.warm_up().await.expect("should warm up");
+        // Assert
+        assert_eq!(start.elapsed(), SLEEPY_DELAY);
+    }
+
+    #[tokio::test]
+    async fn warm_up_allows_sync_get_of_async_singleton() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 9090 })
+            .with_type_async::<AsyncDatabase>()
+            .with_eager_singletons()
+            .build();
+        // Act
+        services.warm_up().await.expect("should warm up");
+        // Assert
+        let db = services.expect::<AsyncDatabase>();
+        assert_eq!(db.config.port, 9090);
+    }
+
+    #[tokio::test]
+    async fn warm_up_skips_lazy_registrations() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async::<AsyncCountedService>()
+            .build();
+        // Act
+        services.warm_up().await.expect("should warm up");
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    fn init_skips_eager_async_singletons() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async_eager::<AsyncCountedService>()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn init_async_runs_async_step() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_async::<AsyncInitTracker>()
+            .with_init_async::<AsyncInitTracker>()
+            .build();
+        // Act
+        let services = services.expect_init_async().await;
+        // Assert
+        let tracker = services.expect::<AsyncInitTracker>();
+        assert!(tracker.initialized.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn init_async_runs_sync_step() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<AsyncInitTracker>()
+            .with_init::<AsyncInitTracker>()
+            .build();
+        // Act
+        let services = services.expect_init_async().await;
+        // Assert
+        let tracker = services.expect::<AsyncInitTracker>();
+        assert!(tracker.initialized.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn init_async_runs_steps_concurrently() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Rendezvous::default())
+            .with_type::<RendezvousA>()
+            .with_init_async::<RendezvousA>()
+            .with_type::<RendezvousB>()
+            .with_init_async::<RendezvousB>()
+            .build();
+        // Act
+        let services = services.expect_init_async().await;
+        // Assert
+        let rendezvous = services.expect::<Rendezvous>();
+        assert!(rendezvous.a.load(Ordering::SeqCst));
+        assert!(rendezvous.b.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn init_async_already_initialized() {
+        // Arrange
+        let services = ServiceBuilder::new().build().expect_init();
+        // Act
+        let output = services.clone().init_async().await;
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::AlreadyInitialized);
+    }
+
+    #[tokio::test]
+    async fn init_async_respects_constraints() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(InitCalls::default())
+            .with_type::<OrderedAsyncA>()
+            .with_init_async::<OrderedAsyncA>()
+            .with_type::<OrderedAsyncB>()
+            .with_init_async::<OrderedAsyncB>()
+            .with_init_after::<OrderedAsyncA, OrderedAsyncB>()
+            .build();
+        // Act
+        let services = services.expect_init_async().await;
+        // Assert
+        let calls = services.expect::<InitCalls>();
+        assert_eq!(*calls.calls.lock().expect("should lock"), vec!["B", "A"]);
+    }
+
+    #[tokio::test]
+    async fn init_async_rollback_aggregates_failures() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_instance(InitCalls::default())
+            .with_type::<OrderedAsyncA>()
+            .with_init_async::<OrderedAsyncA>()
+            .with_init_async::<OrderedAsyncB>()
+            .build();
+        // Act
+        let output = services.clone().init_async().await;
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Aggregate);
+        let calls = services.expect::<InitCalls>();
+        assert_eq!(*calls.calls.lock().expect("should lock"), vec!["A"]);
+        assert!(services.init_async().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn init_async_cycle_returns_error() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Rendezvous::default())
+            .with_type::<RendezvousA>()
+            .with_init_async::<RendezvousA>()
+            .with_type::<RendezvousB>()
+            .with_init_async::<RendezvousB>()
+            .with_init_after::<RendezvousA, RendezvousB>()
+            .with_init_before::<RendezvousA, RendezvousB>()
+            .build();
+        // Act
+        let output = services.init_async().await;
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Cycle);
+    }
+
+    #[test]
+    fn init_with_async_step_returns_error() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_async::<AsyncInitTracker>()
+            .with_init_async::<AsyncInitTracker>()
+            .build();
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Async);
+    }
+
+    #[tokio::test]
+    async fn init_async_constructs_eager_singletons() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async_eager::<AsyncCountedService>()
+            .build();
+        // Act
+        let services = services.expect_init_async().await;
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 1);
+    }
+
+    /// Async singleton that yields while being constructed.
+    struct SlowShared;
+
+    impl FromServicesAsync for SlowShared {
+        type Error = ResolveError;
+        async fn from_services_async(
+            services: &ServiceProvider,
+        ) -> Result<Self, Report<ResolveError>> {
+            let counter = services.get::<ConstructionCounter>()?;
+            yield_now().await;
+            counter.count.fetch_add(1, Ordering::SeqCst);
+            Ok(Self)
+        }
+    }
+
+    /// Delay before a [`Sleepy`] is constructed.
+    const SLEEPY_DELAY: Duration = Duration::from_secs(1);
+
+    /// Async singleton that sleeps while being constructed.
+    struct Sleepy<const N: usize>;
+
+    impl<const N: usize> FromServicesAsync for Sleepy<N> {
+        type Error = ResolveError;
+        async fn from_services_async(
+            _services: &ServiceProvider,
+        ) -> Result<Self, Report<ResolveError>> {
+            sleep(SLEEPY_DELAY).await;
+            Ok(Self)
+        }
+    }
+
+    #[derive(FromServicesAsync)]
+    struct SharedUserA {
+        _shared: Arc<SlowShared>,
+    }
+
+    #[derive(FromServicesAsync)]
+    struct SharedUserB {
+        _shared: Arc<SlowShared>,
+    }
+
+    struct AsyncInitTracker {
+        initialized: AtomicBool,
+    }
+
+    impl FromServices for AsyncInitTracker {
+        type Error = ResolveError;
+        fn from_services(_services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            Ok(Self {
+                initialized: AtomicBool::new(false),
+            })
+        }
+    }
+
+    impl FromServicesAsync for AsyncInitTracker {
+        type Error = ResolveError;
+        async fn from_services_async(
+            _services: &ServiceProvider,
+        ) -> Result<Self, Report<ResolveError>> {
+            Ok(Self {
+                initialized: AtomicBool::new(false),
+            })
+        }
+    }
+
+    impl Init for AsyncInitTracker {
+        fn init(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.initialized.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    impl InitAsync for AsyncInitTracker {
+        async fn init_async(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.initialized.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// Flags set by two init steps that each wait for the other.
+    #[derive(Default)]
+    struct Rendezvous {
+        a: AtomicBool,
+        b: AtomicBool,
+    }
+
+    impl Rendezvous {
+        /// Set one flag then wait until the other is set.
+        async fn meet(&self, mine: &AtomicBool, theirs: &AtomicBool) {
+            mine.store(true, Ordering::SeqCst);
+            while !theirs.load(Ordering::SeqCst) {
+                yield_now().await;
+            }
+        }
+    }
+
+    #[derive(FromServices)]
+    struct RendezvousA {
+        rendezvous: Arc<Rendezvous>,
+    }
+
+    impl InitAsync for RendezvousA {
+        async fn init_async(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            let rendezvous = &self.rendezvous;
+            rendezvous.meet(&rendezvous.a, &rendezvous.b).await;
+            Ok(())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct RendezvousB {
+        rendezvous: Arc<Rendezvous>,
+    }
+
+    impl InitAsync for RendezvousB {
+        async fn init_async(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            let rendezvous = &self.rendezvous;
+            rendezvous.meet(&rendezvous.b, &rendezvous.a).await;
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct InitCalls {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    #[derive(FromServices)]
+    struct OrderedAsyncA {
+        calls: Arc<InitCalls>,
+    }
+
+    impl InitAsync for OrderedAsyncA {
+        async fn init_async(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            yield_now().await;
+            self.calls.calls.lock().expect("should lock").push("A");
+            Ok(())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct OrderedAsyncB {
+        calls: Arc<InitCalls>,
+    }
+
+    impl InitAsync for OrderedAsyncB {
+        async fn init_async(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            yield_now().await;
+            self.calls.calls.lock().expect("should lock").push("B");
+            Ok(())
+        }
+    }
 }
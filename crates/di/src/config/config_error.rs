@@ -0,0 +1,19 @@
+//! Errors returned when loading and binding configuration.
+use crate::prelude::*;
+
+/// Errors returned when loading, binding or validating configuration.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ConfigError {
+    /// A configuration file could not be read.
+    #[error("Failed to read configuration file")]
+    Read,
+    /// A configuration file could not be parsed.
+    #[error("Failed to parse configuration file")]
+    Parse,
+    /// A configuration section could not be deserialized into the target type.
+    #[error("Failed to bind configuration section")]
+    Bind,
+    /// A bound configuration section failed [`Validate`].
+    #[error("Configuration is invalid")]
+    Validate,
+}
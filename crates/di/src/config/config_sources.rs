@@ -0,0 +1,263 @@
+//! Layered configuration sources.
+use crate::prelude::*;
+
+/// Separator between nested keys in environment variable names.
+const ENV_SEPARATOR: &str = "__";
+
+/// Layered configuration sources, from lowest to highest precedence.
+///
+/// 1. Files, in the order they were added
+/// 2. Environment variables with a prefix
+/// 3. In-code overrides, in the order they were added
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ConfigSources {
+    /// TOML or JSON files.
+    pub files: Vec<PathBuf>,
+    /// Environment variable prefixes.
+    pub env_prefixes: Vec<String>,
+    /// Dot separated keys and their values.
+    pub overrides: Vec<(String, JsonValue)>,
+    /// How often files are polled for changes by an [`OptionsMonitor`].
+    pub watch_interval: Option<Duration>,
+}
+
+/// Check applied to a configuration section after it is bound.
+pub(crate) type CheckFn<T> = fn(&T) -> Result<(), Report<ConfigError>>;
+
+impl ConfigSources {
+    /// Load and merge every source using the process environment.
+    ///
+    /// Environment variables whose name or value is not valid Unicode are ignored.
+    pub fn load(&self) -> Result<JsonValue, Report<ConfigError>> {
+        let env = vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        });
+        self.load_with_env(env)
+    }
+
+    /// Load and merge every source using the given environment variables.
+    pub fn load_with_env(
+        &self,
+        env: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<JsonValue, Report<ConfigError>> {
+        let mut root = JsonValue::Object(JsonMap::new());
+        for path in &self.files {
+            merge(&mut root, read_file(path)?);
+        }
+        if !self.env_prefixes.is_empty() {
+            for (name, value) in env {
+                let Some(keys) = self.env_keys(&name) else {
+                    continue;
+                };
+                insert(&mut root, &keys, parse_env_value(&value));
+            }
+        }
+        for (key, value) in &self.overrides {
+            let keys: Vec<String> = key.split('.').map(str::to_owned).collect();
+            insert(&mut root, &keys, value.clone());
+        }
+        Ok(root)
+    }
+
+    /// Load every source and bind a section, then check the bound value.
+    pub fn bind<T: DeserializeOwned>(
+        &self,
+        section: &str,
+        check: CheckFn<T>,
+    ) -> Result<T, Report<ConfigError>> {
+        let root = self.load()?;
+        let value = get_section(&root, section);
+        let config: T = serde_json::from_value(value)
+            .change_context(ConfigError::Bind)
+            .attach("section", section.to_owned())?;
+        check(&config).attach("section", section.to_owned())?;
+        Ok(config)
+    }
+
+    /// Get the nested keys of an environment variable if it has a registered prefix.
+    fn env_keys(&self, name: &str) -> Option<Vec<String>> {
+        self.env_prefixes.iter().find_map(|prefix| {
+            let rest = name.strip_prefix(prefix)?.strip_prefix(ENV_SEPARATOR)?;
+            let keys: Vec<String> = rest.split(ENV_SEPARATOR).map(str::to_lowercase).collect();
+            keys.iter().all(|key| !key.is_empty()).then_some(keys)
+        })
+    }
+}
+
+/// Get a dot separated section of the merged configuration.
+///
+/// A missing section is treated as an empty table so `#[serde(default)]` fields
+/// can still be bound.
+pub(crate) fn get_section(root: &JsonValue, section: &str) -> JsonValue {
+    if section.is_empty() {
+        return root.clone();
+    }
+    section
+        .split('.')
+        .try_fold(root, |value, key| value.get(key))
+        .cloned()
+        .unwrap_or_else(|| JsonValue::Object(JsonMap::new()))
+}
+
+/// Read a TOML or JSON file, choosing the format by extension.
+fn read_file(path: &Path) -> Result<JsonValue, Report<ConfigError>> {
+    let text = read_to_string(path)
+        .change_context(ConfigError::Read)
+        .attach("path", path.display().to_string())?;
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension == "json");
+    if is_json {
+        serde_json::from_str(&text)
+            .change_context(ConfigError::Parse)
+            .attach("path", path.display().to_string())
+    } else {
+        toml::from_str(&text)
+            .change_context(ConfigError::Parse)
+            .attach("path", path.display().to_string())
+    }
+}
+
+/// Parse an environment variable value as JSON, falling back to a string.
+fn parse_env_value(value: &str) -> JsonValue {
+    serde_json::from_str(value).unwrap_or_else(|_| JsonValue::String(value.to_owned()))
+}
+
+/// Recursively merge `overlay` into `base`, with `overlay` taking precedence.
+fn merge(base: &mut JsonValue, overlay: JsonValue) {
+    match (base, overlay) {
+        (JsonValue::Object(base), JsonValue::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Insert a value at a nested key path, creating tables as needed.
+fn insert(root: &mut JsonValue, keys: &[String], value: JsonValue) {
+    let Some((last, parents)) = keys.split_last() else {
+        return;
+    };
+    let mut current = root;
+    for key in parents {
+        if !current.is_object() {
+            *current = JsonValue::Object(JsonMap::new());
+        }
+        let JsonValue::Object(map) = current else {
+            return;
+        };
+        current = map
+            .entry(key.clone())
+            .or_insert_with(|| JsonValue::Object(JsonMap::new()));
+    }
+    if !current.is_object() {
+        *current = JsonValue::Object(JsonMap::new());
+    }
+    if let JsonValue::Object(map) = current {
+        merge_entry(map, last, value);
+    }
+}
+
+/// Merge a value into a table entry.
+fn merge_entry(map: &mut JsonMap<String, JsonValue>, key: &str, value: JsonValue) {
+    match map.get_mut(key) {
+        Some(existing) => merge(existing, value),
+        None => {
+            map.insert(key.to_owned(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn load_with_env_maps_nested_keys() {
+        // Arrange
+        let sources = ConfigSources {
+            env_prefixes: vec!["APP".to_owned()],
+            ..ConfigSources::default()
+        };
+        let env = env(&[
+            ("APP__DATABASE__PORT", "5432"),
+            ("APP__DATABASE__HOST_NAME", "localhost"),
+            ("OTHER__DATABASE__PORT", "1"),
+            ("APPLICATION__DATABASE__PORT", "2"),
+        ]);
+
+        // Act
+        let root = sources.load_with_env(env).expect("should load");
+
+        // Assert
+        assert_eq!(
+            root,
+            json!({ "database": { "port": 5432, "host_name": "localhost" } })
+        );
+    }
+
+    #[test]
+    fn load_with_env_applies_overrides_last() {
+        // Arrange
+        let sources = ConfigSources {
+            env_prefixes: vec!["APP".to_owned()],
+            overrides: vec![("database.port".to_owned(), json!(6543))],
+            ..ConfigSources::default()
+        };
+        let env = env(&[
+            ("APP__DATABASE__PORT", "5432"),
+            ("APP__DATABASE__HOST", "db"),
+        ]);
+
+        // Act
+        let root = sources.load_with_env(env).expect("should load");
+
+        // Assert
+        assert_eq!(root, json!({ "database": { "port": 6543, "host": "db" } }));
+    }
+
+    #[test]
+    fn load_reports_missing_file() {
+        // Arrange
+        let sources = ConfigSources {
+            files: vec![PathBuf::from("does-not-exist.toml")],
+            ..ConfigSources::default()
+        };
+
+        // Act
+        let result = sources.load_with_env(Vec::new());
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &ConfigError::Read);
+    }
+
+    #[test]
+    fn get_section_returns_empty_table_when_missing() {
+        // Arrange
+        let root = json!({ "cache": { "redis": { "url": "redis://" } } });
+
+        // Act
+        let redis = get_section(&root, "cache.redis");
+        let missing = get_section(&root, "database");
+
+        // Assert
+        assert_eq!(redis, json!({ "url": "redis://" }));
+        assert_eq!(missing, json!({}));
+    }
+}
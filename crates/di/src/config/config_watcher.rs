@@ -0,0 +1,100 @@
+//! Shared file watcher for live-reloading configuration.
+use crate::prelude::*;
+
+/// Monitor that can be reloaded by a [`ConfigWatcher`].
+pub(crate) trait WatchedMonitor: Send + Sync {
+    /// Reload the monitor, logging any failure.
+    fn reload_watched(&self);
+}
+
+/// Single poller that reloads every [`OptionsMonitor`] bound to the same sources.
+///
+/// Created once per provider when [`ServiceBuilder::with_config_watch`] is used.
+/// The polling thread is started by the first monitor and stops once the watcher
+/// is dropped.
+pub(crate) struct ConfigWatcher {
+    /// Files polled for changes.
+    files: Vec<PathBuf>,
+    /// How often the files are polled.
+    interval: Duration,
+    /// Monitors reloaded when a file changes.
+    monitors: Mutex<Vec<Weak<dyn WatchedMonitor>>>,
+    /// Whether the polling thread has been started.
+    started: AtomicBool,
+}
+
+impl ConfigWatcher {
+    /// Create a watcher for the files of `sources`.
+    ///
+    /// Returns `None` if watching is not enabled.
+    pub fn new(sources: &ConfigSources) -> Option<Arc<Self>> {
+        let interval = sources.watch_interval?;
+        Some(Arc::new(Self {
+            files: sources.files.clone(),
+            interval,
+            monitors: Mutex::new(Vec::new()),
+            started: AtomicBool::new(false),
+        }))
+    }
+
+    /// Reload `monitor` whenever a file changes, starting the polling thread if needed.
+    pub fn watch(self: &Arc<Self>, monitor: Weak<dyn WatchedMonitor>) {
+        let mut monitors = self.lock_monitors();
+        monitors.retain(|monitor| monitor.strong_count() > 0);
+        monitors.push(monitor);
+        drop(monitors);
+        if !self.started.swap(true, Ordering::SeqCst) {
+            let watcher = Arc::downgrade(self);
+            let interval = self.interval;
+            let stamps = file_stamps(&self.files);
+            thread::spawn(move || poll(&watcher, interval, stamps));
+        }
+    }
+
+    /// Get the monitors that have not been dropped.
+    pub fn monitors(&self) -> Vec<Arc<dyn WatchedMonitor>> {
+        self.lock_monitors()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect()
+    }
+
+    /// Lock the watched monitors.
+    fn lock_monitors(&self) -> MutexGuard<'_, Vec<Weak<dyn WatchedMonitor>>> {
+        self.monitors
+            .lock()
+            .expect("should be able to lock monitors")
+    }
+}
+
+/// Poll files for changes and reload every monitor until the watcher is dropped.
+fn poll(watcher: &Weak<ConfigWatcher>, interval: Duration, mut stamps: Vec<Option<FileStamp>>) {
+    loop {
+        thread::sleep(interval);
+        let Some(watcher) = watcher.upgrade() else {
+            return;
+        };
+        let latest = file_stamps(&watcher.files);
+        if latest == stamps {
+            continue;
+        }
+        stamps = latest;
+        for monitor in watcher.monitors() {
+            monitor.reload_watched();
+        }
+    }
+}
+
+/// Modification time and size used to detect file changes.
+type FileStamp = (SystemTime, u64);
+
+/// Get the current stamp of each file, or `None` if it cannot be read.
+fn file_stamps(files: &[PathBuf]) -> Vec<Option<FileStamp>> {
+    files
+        .iter()
+        .map(|path| {
+            let metadata = metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+//! Typed configuration binding.
+mod config_error;
+mod config_sources;
+mod config_watcher;
+mod options_monitor;
+mod service_builder_config;
+mod validate;
+
+pub use config_error::*;
+pub(crate) use config_sources::*;
+pub(crate) use config_watcher::*;
+pub use options_monitor::*;
+pub use validate::*;
@@ -0,0 +1,265 @@
+//! Live-reloading configuration.
+use crate::prelude::*;
+
+/// Callback notified with the new value after a successful reload.
+type Subscriber<T> = Arc<dyn Fn(Arc<T>) + Send + Sync>;
+
+/// Configuration section that can be reloaded without a restart.
+///
+/// Registered via [`ServiceBuilder::with_options_monitor`]. The value is swapped
+/// atomically so [`current`](OptionsMonitor::current) always returns a complete
+/// value.
+pub struct OptionsMonitor<T> {
+    state: Arc<MonitorState<T>>,
+}
+
+/// State shared between the monitor and the [`ConfigWatcher`].
+struct MonitorState<T> {
+    sources: ConfigSources,
+    section: String,
+    check: CheckFn<T>,
+    value: RwLock<Arc<T>>,
+    subscribers: Mutex<Vec<Subscriber<T>>>,
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> OptionsMonitor<T> {
+    /// Bind the initial value and register with the watcher if watching is enabled.
+    pub(crate) fn new(
+        sources: ConfigSources,
+        section: String,
+        check: CheckFn<T>,
+        watcher: Option<&Arc<ConfigWatcher>>,
+    ) -> Result<Self, Report<ConfigError>> {
+        let value = sources.bind(&section, check)?;
+        let state = Arc::new(MonitorState {
+            sources,
+            section,
+            check,
+            value: RwLock::new(Arc::new(value)),
+            subscribers: Mutex::new(Vec::new()),
+        });
+        if let Some(watcher) = watcher {
+            let monitor: Weak<MonitorState<T>> = Arc::downgrade(&state);
+            watcher.watch(monitor);
+        }
+        Ok(Self { state })
+    }
+
+    /// Get the latest value.
+    #[must_use]
+    pub fn current(&self) -> Arc<T> {
+        self.state
+            .value
+            .read()
+            .expect("should be able to read value")
+            .clone()
+    }
+
+    /// Call `callback` with the new value after every successful reload.
+    ///
+    /// Callbacks are called without holding any lock, so they may subscribe or
+    /// reload the monitor themselves.
+    pub fn subscribe(&self, callback: impl Fn(Arc<T>) + Send + Sync + 'static) {
+        self.state
+            .subscribers
+            .lock()
+            .expect("should be able to lock subscribers")
+            .push(Arc::new(callback));
+    }
+
+    /// Reload the value from the configuration sources.
+    ///
+    /// On failure the previous value is kept and the error is returned.
+    pub fn reload(&self) -> Result<(), Report<ConfigError>> {
+        self.state.reload()
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> MonitorState<T> {
+    /// Bind a new value, swap it in and notify subscribers.
+    fn reload(&self) -> Result<(), Report<ConfigError>> {
+        let value = Arc::new(self.sources.bind(&self.section, self.check)?);
+        *self.value.write().expect("should be able to write value") = value.clone();
+        trace!(section = self.section, "Reloaded configuration");
+        let subscribers = self
+            .subscribers
+            .lock()
+            .expect("should be able to lock subscribers")
+            .clone();
+        for subscriber in subscribers {
+            subscriber(value.clone());
+        }
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> WatchedMonitor for MonitorState<T> {
+    fn reload_watched(&self) {
+        if let Err(error) = self.reload() {
+            warn!(
+                section = self.section,
+                ?error,
+                "Failed to reload configuration"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
+    use std::process::id as process_id;
+
+    #[derive(Debug, Deserialize)]
+    struct Limits {
+        rate: u32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Quotas {
+        size: u32,
+    }
+
+    fn config_file(name: &str, contents: &str) -> PathBuf {
+        let path = temp_dir().join(format!("studiole-di-{name}-{}.toml", process_id()));
+        write(&path, contents).expect("should write file");
+        path
+    }
+
+    #[test]
+    fn reload_swaps_value_and_notifies_subscribers() {
+        // Arrange
+        let path = config_file("monitor-reload", "[limits]\nrate = 1\n");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_options_monitor::<Limits>("limits")
+            .build();
+        let monitor = services.expect::<OptionsMonitor<Limits>>();
+        let notified = Arc::new(Mutex::new(Vec::new()));
+        let sink = notified.clone();
+        monitor.subscribe(move |limits| sink.lock().expect("should lock").push(limits.rate));
+        let before = monitor.current();
+
+        // Act
+        write(&path, "[limits]\nrate = 2\n").expect("should write file");
+        let result = monitor.reload();
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        assert!(result.is_ok());
+        assert_eq!(before.rate, 1);
+        assert_eq!(monitor.current().rate, 2);
+        assert_eq!(*notified.lock().expect("should lock"), vec![2]);
+    }
+
+    #[test]
+    fn reload_keeps_previous_value_on_failure() {
+        // Arrange
+        let path = config_file("monitor-failure", "[limits]\nrate = 1\n");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_options_monitor::<Limits>("limits")
+            .build();
+        let monitor = services.expect::<OptionsMonitor<Limits>>();
+
+        // Act
+        write(&path, "[limits]\nrate = \"fast\"\n").expect("should write file");
+        let result = monitor.reload();
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &ConfigError::Bind);
+        assert_eq!(monitor.current().rate, 1);
+    }
+
+    #[test]
+    fn subscriber_can_subscribe_and_reload() {
+        // Arrange
+        let path = config_file("monitor-reentrant", "[limits]\nrate = 1\n");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_options_monitor::<Limits>("limits")
+            .build();
+        let monitor = services.expect::<OptionsMonitor<Limits>>();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let inner = monitor.clone();
+        let counter = calls.clone();
+        monitor.subscribe(move |_limits| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                inner.subscribe(|_limits| {});
+                inner.reload().expect("should reload");
+            }
+        });
+
+        // Act
+        let result = monitor.reload();
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn watch_reloads_changed_file() {
+        // Arrange
+        let path = config_file("monitor-watch", "[limits]\nrate = 1\n");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_config_watch(Duration::from_millis(10))
+            .with_options_monitor::<Limits>("limits")
+            .build();
+        let monitor = services.expect::<OptionsMonitor<Limits>>();
+
+        // Act
+        write(&path, "[limits]\nrate = 100\n").expect("should write file");
+        for _ in 0..500 {
+            if monitor.current().rate == 100 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        assert_eq!(monitor.current().rate, 100);
+    }
+
+    #[test]
+    fn watch_shares_one_watcher_between_monitors() {
+        // Arrange
+        let path = config_file("monitor-shared", "[limits]\nrate = 1\n[quotas]\nsize = 1\n");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_config_watch(Duration::from_millis(10))
+            .with_options_monitor::<Limits>("limits")
+            .with_options_monitor::<Quotas>("quotas")
+            .build();
+        let limits = services.expect::<OptionsMonitor<Limits>>();
+        let quotas = services.expect::<OptionsMonitor<Quotas>>();
+
+        // Act
+        write(&path, "[limits]\nrate = 2\n[quotas]\nsize = 2\n").expect("should write file");
+        for _ in 0..500 {
+            if limits.current().rate == 2 && quotas.current().size == 2 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        let watcher = services
+            .registry
+            .config_watcher
+            .as_ref()
+            .expect("should have watcher");
+        assert_eq!(watcher.monitors().len(), 2);
+        assert_eq!(limits.current().rate, 2);
+        assert_eq!(quotas.current().size, 2);
+    }
+}
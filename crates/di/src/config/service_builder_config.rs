@@ -0,0 +1,261 @@
+//! Configuration registration.
+use crate::prelude::*;
+
+impl ServiceBuilder {
+    /// Bind a configuration section and register it as a singleton.
+    ///
+    /// - `section` is a dot separated path such as `"database"` or `"cache.redis"`,
+    ///   or an empty string for the whole configuration
+    /// - A missing section is bound from an empty table
+    /// - The section is bound eagerly during [`ServiceProvider::init`], or on first
+    ///   resolution if the provider is not initialized
+    ///
+    /// Binding failures are returned as [`ResolveError::Config`].
+    #[must_use]
+    pub fn with_config<T: DeserializeOwned + Send + Sync + 'static>(self, section: &str) -> Self {
+        self.register_config::<T>(section, |_| Ok(()))
+    }
+
+    /// Bind a configuration section, check it with [`Validate`], and register it
+    /// as a singleton.
+    ///
+    /// See [`with_config`](ServiceBuilder::with_config).
+    #[must_use]
+    pub fn with_config_validated<T: DeserializeOwned + Validate + Send + Sync + 'static>(
+        self,
+        section: &str,
+    ) -> Self {
+        self.register_config::<T>(section, |config| {
+            config.validate().change_context(ConfigError::Validate)
+        })
+    }
+
+    /// Bind a configuration section into an [`OptionsMonitor`] and register the
+    /// monitor as a singleton.
+    ///
+    /// Inject `OptionsMonitor<T>` rather than `Arc<T>` to always see the latest
+    /// value. See [`with_config`](ServiceBuilder::with_config) for how the section is
+    /// bound.
+    #[must_use]
+    pub fn with_options_monitor<T: DeserializeOwned + Send + Sync + 'static>(
+        self,
+        section: &str,
+    ) -> Self {
+        self.register_options_monitor::<T>(section, |_| Ok(()))
+    }
+
+    /// Bind a configuration section into an [`OptionsMonitor`] that checks every
+    /// value with [`Validate`].
+    ///
+    /// See [`with_options_monitor`](ServiceBuilder::with_options_monitor).
+    #[must_use]
+    pub fn with_options_monitor_validated<
+        T: DeserializeOwned + Validate + Send + Sync + 'static,
+    >(
+        self,
+        section: &str,
+    ) -> Self {
+        self.register_options_monitor::<T>(section, |config| {
+            config.validate().change_context(ConfigError::Validate)
+        })
+    }
+
+    /// Poll configuration files for changes and reload every [`OptionsMonitor`].
+    ///
+    /// Files are checked for a changed modification time or size every `interval`
+    /// by a single thread shared by every monitor of the provider.
+    #[must_use]
+    pub fn with_config_watch(mut self, interval: Duration) -> Self {
+        self.config.watch_interval = Some(interval);
+        self
+    }
+
+    /// Add a TOML or JSON configuration file.
+    ///
+    /// Files ending in `.json` are parsed as JSON, any other file as TOML. Files
+    /// added later take precedence.
+    #[must_use]
+    pub fn with_config_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.files.push(path.into());
+        self
+    }
+
+    /// Read configuration from environment variables with a prefix.
+    ///
+    /// Nested keys are separated by a double underscore and lowercased, so
+    /// `APP__DATABASE__PORT` sets `database.port` for the prefix `APP`. Values are
+    /// parsed as JSON, falling back to a string. Takes precedence over files.
+    #[must_use]
+    pub fn with_config_env(mut self, prefix: &str) -> Self {
+        self.config.env_prefixes.push(prefix.to_owned());
+        self
+    }
+
+    /// Set a configuration value by dot separated key.
+    ///
+    /// Takes precedence over files and environment variables.
+    #[must_use]
+    pub fn with_config_override(mut self, key: &str, value: impl Into<JsonValue>) -> Self {
+        self.config.overrides.push((key.to_owned(), value.into()));
+        self
+    }
+
+    /// Register an eager singleton factory that binds and checks a section.
+    fn register_config<T: DeserializeOwned + Send + Sync + 'static>(
+        self,
+        section: &str,
+        check: CheckFn<T>,
+    ) -> Self {
+        let section = section.to_owned();
+        let factory: SyncFactory = Arc::new(move |services: &ServiceProvider| {
+            let config: T = services
+                .registry
+                .config
+                .bind(&section, check)
+                .change_context(ResolveError::Config)?;
+            Ok(config.into_any())
+        });
+        self.register_eager_factory::<T>(factory)
+    }
+
+    /// Register an eager singleton factory that binds a section into an
+    /// [`OptionsMonitor`].
+    fn register_options_monitor<T: DeserializeOwned + Send + Sync + 'static>(
+        self,
+        section: &str,
+        check: CheckFn<T>,
+    ) -> Self {
+        let section = section.to_owned();
+        let factory: SyncFactory = Arc::new(move |services: &ServiceProvider| {
+            let registry = &services.registry;
+            let monitor = OptionsMonitor::<T>::new(
+                registry.config.clone(),
+                section.clone(),
+                check,
+                registry.config_watcher.as_ref(),
+            )
+            .change_context(ResolveError::Config)?;
+            Ok(monitor.into_any())
+        });
+        self.register_eager_factory::<OptionsMonitor<T>>(factory)
+    }
+
+    /// Insert an eager singleton registration with a custom factory.
+    fn register_eager_factory<T: 'static>(mut self, factory: SyncFactory) -> Self {
+        self.insert_factory(
+            TypeId::of::<T>(),
+            Registration {
+                type_name: type_name::<T>(),
+                lifetime: Arc::new(SingletonLifetime),
+                eager: true,
+                is_async: false,
+                profile: None,
+                factory,
+                async_factory: None,
+            },
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
+    use std::process::id as process_id;
+
+    #[derive(Debug, Deserialize)]
+    struct DatabaseConfig {
+        host: String,
+        port: u16,
+        #[serde(default)]
+        pool_size: usize,
+    }
+
+    impl Validate for DatabaseConfig {
+        fn validate(&self) -> Result<(), Report<ConfigError>> {
+            if self.port == 0 {
+                return Err(Report::new(ConfigError::Validate)).attach("port", self.port);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn with_config_binds_section_from_overrides() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_config_override("database.host", "localhost")
+            .with_config_override("database.port", 5432)
+            .with_config::<DatabaseConfig>("database")
+            .build();
+
+        // Act
+        let config = services.expect::<DatabaseConfig>();
+
+        // Assert
+        assert_eq!(config.host, "localhost");
+        assert_eq!(config.port, 5432);
+        assert_eq!(config.pool_size, 0);
+    }
+
+    #[test]
+    fn with_config_layers_overrides_over_files() {
+        // Arrange
+        let path = temp_dir().join(format!("studiole-di-config-{}.toml", process_id()));
+        write(
+            &path,
+            "[database]\nhost = \"file\"\nport = 1\npool_size = 4\n",
+        )
+        .expect("should write file");
+        let services = ServiceBuilder::new()
+            .with_config_file(&path)
+            .with_config_override("database.port", 5432)
+            .with_config::<DatabaseConfig>("database")
+            .build();
+
+        // Act
+        let config = services.expect::<DatabaseConfig>();
+
+        // Assert
+        remove_file(&path).expect("should remove file");
+        assert_eq!(config.host, "file");
+        assert_eq!(config.port, 5432);
+        assert_eq!(config.pool_size, 4);
+    }
+
+    #[test]
+    fn with_config_reports_bind_failure() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_config_override("database.port", "not a number")
+            .with_config::<DatabaseConfig>("database")
+            .build();
+
+        // Act
+        let result = services.get::<DatabaseConfig>();
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &ResolveError::Config);
+    }
+
+    #[test]
+    fn with_config_validated_fails_init() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_config_override("database.host", "localhost")
+            .with_config_override("database.port", 0)
+            .with_config_validated::<DatabaseConfig>("database")
+            .build();
+
+        // Act
+        let result = services.init();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &InitError::Init);
+    }
+}
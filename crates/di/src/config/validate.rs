@@ -0,0 +1,10 @@
+//! Configuration validation trait.
+use crate::prelude::*;
+
+/// Check a bound configuration section.
+///
+/// Registered via [`ServiceBuilder::with_config_validated`].
+pub trait Validate {
+    /// Validate the configuration.
+    fn validate(&self) -> Result<(), Report<ConfigError>>;
+}
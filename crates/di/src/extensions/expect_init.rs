@@ -8,6 +8,14 @@ impl ServiceProvider {
     pub fn expect_init(self) -> Self {
         self.init().expect("should be able to init services")
     }
+
+    /// Run all registered init steps asynchronously or panic.
+    #[cfg(feature = "async")]
+    pub async fn expect_init_async(self) -> Self {
+        self.init_async()
+            .await
+            .expect("should be able to init services")
+    }
 }
 
 #[cfg(test)]
@@ -18,4 +26,10 @@ mod tests {
     fn service_provider_expect_init() {
         let _services = ServiceBuilder::new().build().expect_init();
     }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn service_provider_expect_init_async() {
+        let _services = ServiceBuilder::new().build().expect_init_async().await;
+    }
 }
@@ -0,0 +1,191 @@
+//! Handle that creates transients on demand.
+use crate::prelude::*;
+
+/// Function that creates the service of a [`Factory`].
+type FactoryCreateFn<T> = fn(&ServiceProvider) -> Result<Arc<T>, Report<ResolveError>>;
+
+/// Handle that creates a new transient on every call.
+///
+/// Inject a `Factory<T>` instead of the [`ServiceProvider`] so a long-lived service
+/// can declare that it constructs `T` per unit of work. Resolve with
+/// [`ServiceProvider::get_factory`], or `get_factory_trait` for a trait object, or
+/// declare a `Factory<T>` field on a struct deriving [`FromServices`].
+///
+/// - Getting the handle returns [`ResolveError::NotTransient`] if `T` is not
+///   registered as a transient, so a misconfigured consumer fails when it is
+///   constructed rather than on first use
+/// - The check is not made at registration, as [`ServiceBuilder::build`] cannot
+///   fail and the handles a consumer needs are only known once it is constructed.
+///   Register the consumer with
+///   [`with_type_eager`](ServiceBuilder::with_type_eager) to report the error
+///   from [`ServiceProvider::init`]
+/// - The handle holds a weak reference to the provider. Creating after the provider
+///   is dropped returns [`ResolveError::Dropped`]
+pub struct Factory<T: ?Sized> {
+    provider: Weak<ServiceRegistry>,
+    creator: FactoryCreateFn<T>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Factory<T> {
+    /// Create a handle that constructs with `creator`.
+    pub(crate) fn new(services: &ServiceProvider, creator: FactoryCreateFn<T>) -> Self {
+        Self {
+            provider: services.downgrade(),
+            creator,
+        }
+    }
+
+    /// Construct a new instance.
+    pub fn create(&self) -> Result<Arc<T>, Report<ResolveError>> {
+        let services = ServiceProvider::upgrade(&self.provider, type_name::<T>())?;
+        (self.creator)(&services)
+    }
+}
+
+impl<T: ?Sized> Clone for Factory<T> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            creator: self.creator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_returns_new_instance() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_transient::<MemoryCache>()
+            .build();
+        let factory = services
+            .get_factory::<MemoryCache>()
+            .expect("should create factory");
+
+        // Act
+        let first = factory.create().expect("should create");
+        first.set("key", "hello");
+        let second = factory.create().expect("should create");
+
+        // Assert
+        assert_eq!(second.get("key"), None);
+    }
+
+    #[test]
+    fn derived_field() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_transient::<CountedService>()
+            .with_type::<DerivedWorker>()
+            .build();
+        let worker = services.expect::<DerivedWorker>();
+        let counter = services.expect::<ConstructionCounter>();
+        let before = counter.get();
+
+        // Act
+        for _ in 0..3 {
+            let _service = worker.services.create().expect("should create");
+        }
+
+        // Assert
+        assert_eq!(before, 0);
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn singleton_returns_not_transient() {
+        // Arrange
+        let services = ServiceBuilder::new().with_type::<MemoryCache>().build();
+
+        // Act
+        let result = services.get_factory::<MemoryCache>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotTransient);
+    }
+
+    #[test]
+    fn instance_returns_not_transient() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+
+        // Act
+        let result = services.get_factory::<Config>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotTransient);
+    }
+
+    #[test]
+    fn unregistered_type_returns_not_found() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+
+        // Act
+        let result = services.get_factory::<MemoryCache>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn eager_consumer_fails_init() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type::<CountedService>()
+            .with_type_eager::<DerivedWorker>()
+            .build();
+
+        // Act
+        let result = services.init();
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolves_from_parent() {
+        // Arrange
+        let parent = ServiceBuilder::new()
+            .with_type_transient::<MemoryCache>()
+            .build();
+        let child = parent.child(|builder| builder);
+
+        // Act
+        let factory = child
+            .get_factory::<MemoryCache>()
+            .expect("should create factory");
+
+        // Assert
+        assert!(factory.create().is_ok());
+    }
+
+    #[cfg(feature = "traits")]
+    #[test]
+    fn trait_object() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_trait_transient::<dyn Get, MemoryCache>()
+            .build();
+        let factory = services
+            .get_factory_trait::<dyn Get>()
+            .expect("should create factory");
+
+        // Act
+        let first = factory.create().expect("should create");
+        let second = factory.create().expect("should create");
+
+        // Assert
+        assert!(!Arc::ptr_eq(&first, &second));
+    }
+}
@@ -0,0 +1,84 @@
+//! Handle that creates transients asynchronously on demand.
+use crate::prelude::*;
+
+/// Function that creates the service of a [`FactoryAsync`].
+type FactoryAsyncCreateFn<T> =
+    fn(ServiceProvider) -> BoxFuture<Result<Arc<T>, Report<ResolveError>>>;
+
+/// Handle that creates a new transient asynchronously on every call.
+///
+/// The async counterpart of [`Factory`] for services registered with
+/// `with_type_async_transient` or `with_trait_async_transient`. Resolve with
+/// [`ServiceProvider::get_factory_async`], or `get_factory_trait_async` for a trait
+/// object, or declare a `FactoryAsync<T>` field on a struct deriving
+/// [`FromServices`] or [`FromServicesAsync`].
+pub struct FactoryAsync<T: ?Sized> {
+    provider: Weak<ServiceRegistry>,
+    creator: FactoryAsyncCreateFn<T>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> FactoryAsync<T> {
+    /// Create a handle that constructs with `creator`.
+    pub(crate) fn new(services: &ServiceProvider, creator: FactoryAsyncCreateFn<T>) -> Self {
+        Self {
+            provider: services.downgrade(),
+            creator,
+        }
+    }
+
+    /// Construct a new instance.
+    pub async fn create(&self) -> Result<Arc<T>, Report<ResolveError>> {
+        let services = ServiceProvider::upgrade(&self.provider, type_name::<T>())?;
+        (self.creator)(services).await
+    }
+}
+
+impl<T: ?Sized> Clone for FactoryAsync<T> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            creator: self.creator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn create_returns_new_instance() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async_transient::<AsyncCountedService>()
+            .build();
+        let factory = services
+            .get_factory_async::<AsyncCountedService>()
+            .expect("should create factory");
+
+        // Act
+        let first = factory.create().await.expect("should create");
+        let second = factory.create().await.expect("should create");
+
+        // Assert
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(services.expect::<ConstructionCounter>().get(), 2);
+    }
+
+    #[tokio::test]
+    async fn singleton_returns_not_transient() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type_async::<AsyncDatabase>()
+            .build();
+
+        // Act
+        let result = services.get_factory_async::<AsyncDatabase>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotTransient);
+    }
+}
@@ -0,0 +1,9 @@
+//! On-demand transient construction.
+mod factory;
+#[cfg(feature = "async")]
+mod factory_async;
+mod service_provider_factory;
+
+pub use factory::*;
+#[cfg(feature = "async")]
+pub use factory_async::*;
@@ -0,0 +1,79 @@
+//! On-demand transient construction.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Get a handle that creates a new instance of a concrete type on every call.
+    ///
+    /// - Returns [`ResolveError::NotFound`] if the type is not registered
+    /// - Returns [`ResolveError::NotTransient`] if the type is not registered as a
+    ///   transient
+    pub fn get_factory<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<Factory<T>, Report<ResolveError>> {
+        self.check_transient(TypeId::of::<T>(), type_name::<T>())?;
+        Ok(Factory::new(self, ServiceProvider::get::<T>))
+    }
+
+    /// Get a handle that creates a new trait object on every call.
+    ///
+    /// - Returns [`ResolveError::NotFound`] if the trait is not registered
+    /// - Returns [`ResolveError::NotTransient`] if the trait is not registered as a
+    ///   transient
+    #[cfg(feature = "traits")]
+    pub fn get_factory_trait<Trait: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<Factory<Trait>, Report<ResolveError>> {
+        self.check_transient(TypeId::of::<Arc<Trait>>(), type_name::<Arc<Trait>>())?;
+        Ok(Factory::new(self, ServiceProvider::get_trait::<Trait>))
+    }
+
+    /// Get a handle that creates a new instance of a concrete type asynchronously on
+    /// every call.
+    ///
+    /// - Returns [`ResolveError::NotFound`] if the type is not registered
+    /// - Returns [`ResolveError::NotTransient`] if the type is not registered as a
+    ///   transient
+    #[cfg(feature = "async")]
+    pub fn get_factory_async<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<FactoryAsync<T>, Report<ResolveError>> {
+        self.check_transient(TypeId::of::<T>(), type_name::<T>())?;
+        Ok(FactoryAsync::new(self, |services| {
+            Box::pin(async move { services.get_async::<T>().await })
+        }))
+    }
+
+    /// Get a handle that creates a new trait object asynchronously on every call.
+    ///
+    /// - Returns [`ResolveError::NotFound`] if the trait is not registered
+    /// - Returns [`ResolveError::NotTransient`] if the trait is not registered as a
+    ///   transient
+    #[cfg(all(feature = "traits", feature = "async"))]
+    pub fn get_factory_trait_async<Trait: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<FactoryAsync<Trait>, Report<ResolveError>> {
+        self.check_transient(TypeId::of::<Arc<Trait>>(), type_name::<Arc<Trait>>())?;
+        Ok(FactoryAsync::new(self, |services| {
+            Box::pin(async move { services.get_trait_async::<Trait>().await })
+        }))
+    }
+
+    /// Check the type is registered as a transient by this provider or the nearest
+    /// parent that registers it.
+    fn check_transient(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<(), Report<ResolveError>> {
+        let registry = &self.registry;
+        let is_transient = match registry.factories.get(&type_id) {
+            Some(registration) => registration.lifetime.describe() == ServiceLifetime::Transient,
+            None if registry.instance_registrations.contains_key(&type_id) => false,
+            None => return self.parent(type_name)?.check_transient(type_id, type_name),
+        };
+        if !is_transient {
+            return Err(Report::new(ResolveError::NotTransient)).attach("type", type_name);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! Health check traits and results.
+use crate::prelude::*;
+
+/// Report the health of a service.
+///
+/// Registered via [`ServiceBuilder::with_health_check`]. Checks run on a blocking
+/// thread so a slow check cannot stall the others.
+pub trait HealthCheck: Send + Sync + 'static {
+    /// Check the health of the service.
+    fn check_health(&self) -> HealthCheckResult;
+}
+
+/// Report the health of a service asynchronously.
+///
+/// Registered via [`ServiceBuilder::with_health_check_async`].
+pub trait HealthCheckAsync: Send + Sync + 'static {
+    /// Check the health of the service asynchronously.
+    fn check_health_async(&self) -> impl Future<Output = HealthCheckResult> + Send;
+}
+
+/// Health of a service, ordered from best to worst.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HealthStatus {
+    /// The service is working normally.
+    #[default]
+    Healthy,
+    /// The service is working with reduced functionality or performance.
+    Degraded,
+    /// The service is not working.
+    Unhealthy,
+}
+
+/// Result of a single health check.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthCheckResult {
+    /// Health of the service.
+    pub status: HealthStatus,
+    /// Optional explanation of the status.
+    pub description: Option<String>,
+}
+
+impl HealthCheckResult {
+    /// Create a [`HealthStatus::Healthy`] result.
+    #[must_use]
+    pub fn healthy() -> Self {
+        Self {
+            status: HealthStatus::Healthy,
+            description: None,
+        }
+    }
+
+    /// Create a [`HealthStatus::Degraded`] result.
+    #[must_use]
+    pub fn degraded(description: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Degraded,
+            description: Some(description.into()),
+        }
+    }
+
+    /// Create a [`HealthStatus::Unhealthy`] result.
+    #[must_use]
+    pub fn unhealthy(description: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Unhealthy,
+            description: Some(description.into()),
+        }
+    }
+}
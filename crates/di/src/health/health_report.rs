@@ -0,0 +1,48 @@
+//! Aggregated health report.
+use crate::prelude::*;
+
+/// Aggregated result of every registered health check.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HealthReport {
+    /// Worst status of any entry, or [`HealthStatus::Healthy`] if there are none.
+    pub status: HealthStatus,
+    /// Result of each check in registration order.
+    pub entries: Vec<HealthEntry>,
+}
+
+/// Result of the health check for a single service type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HealthEntry {
+    /// Name of the checked service type.
+    pub type_name: &'static str,
+    /// Health of the service.
+    pub status: HealthStatus,
+    /// Optional explanation of the status.
+    pub description: Option<String>,
+    /// Time taken by the check.
+    pub duration: Duration,
+}
+
+impl HealthReport {
+    /// Create a report from the individual entries.
+    #[must_use]
+    pub fn new(entries: Vec<HealthEntry>) -> Self {
+        let status = entries
+            .iter()
+            .map(|entry| entry.status)
+            .max()
+            .unwrap_or_default();
+        Self { status, entries }
+    }
+
+    /// Get the entry for a service type.
+    #[must_use]
+    pub fn get<T: 'static>(&self) -> Option<&HealthEntry> {
+        let type_name = type_name::<T>();
+        self.entries
+            .iter()
+            .find(|entry| entry.type_name == type_name)
+    }
+}
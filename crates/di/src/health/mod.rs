@@ -0,0 +1,9 @@
+//! Service health checks.
+mod health_check;
+mod health_report;
+mod service_builder_health;
+mod service_provider_health;
+
+pub use health_check::*;
+pub use health_report::*;
+pub(crate) use service_builder_health::*;
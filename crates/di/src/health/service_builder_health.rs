@@ -0,0 +1,84 @@
+//! Health check registration.
+use crate::prelude::*;
+
+/// Default time allowed for each health check.
+pub(crate) const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Closure that resolves a service and checks its health.
+pub(crate) type HealthCheckFn =
+    Arc<dyn Fn(ServiceProvider) -> BoxFuture<HealthCheckResult> + Send + Sync>;
+
+/// Internal health check entry pairing a service type with its check closure.
+#[derive(Clone)]
+pub(crate) struct HealthCheckRegistration {
+    /// Type of the checked service.
+    pub type_id: TypeId,
+    /// Name of the checked service type.
+    pub type_name: &'static str,
+    /// Closure that resolves and checks the service.
+    pub check: HealthCheckFn,
+}
+
+impl ServiceBuilder {
+    /// Mark a type for health checking during [`ServiceProvider::check_health`].
+    ///
+    /// The service is resolved via [`get_async`](ServiceProvider::get_async) so it may
+    /// be registered with either [`with_type`](ServiceBuilder::with_type) or
+    /// [`with_type_async`](ServiceBuilder::with_type_async).
+    #[must_use]
+    pub fn with_health_check<T: HealthCheck>(mut self) -> Self {
+        let check: HealthCheckFn = Arc::new(|services: ServiceProvider| {
+            Box::pin(async move {
+                let instance = match services.get_async::<T>().await {
+                    Ok(instance) => instance,
+                    Err(error) => return resolve_failure(&error),
+                };
+                spawn_blocking(move || instance.check_health())
+                    .await
+                    .unwrap_or_else(|error| {
+                        HealthCheckResult::unhealthy(format!("Health check panicked: {error}"))
+                    })
+            })
+        });
+        self.health_checks.push(HealthCheckRegistration {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            check,
+        });
+        self
+    }
+
+    /// Mark a type for async health checking during [`ServiceProvider::check_health`].
+    #[must_use]
+    pub fn with_health_check_async<T: HealthCheckAsync>(mut self) -> Self {
+        let check: HealthCheckFn = Arc::new(|services: ServiceProvider| {
+            Box::pin(async move {
+                match services.get_async::<T>().await {
+                    Ok(instance) => instance.check_health_async().await,
+                    Err(error) => resolve_failure(&error),
+                }
+            })
+        });
+        self.health_checks.push(HealthCheckRegistration {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            check,
+        });
+        self
+    }
+
+    /// Set the time allowed for each health check.
+    ///
+    /// A check that does not complete in time is reported as
+    /// [`HealthStatus::Unhealthy`]. Defaults to 5 seconds.
+    #[must_use]
+    pub fn with_health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = Some(timeout);
+        self
+    }
+}
+
+/// Report a service that could not be resolved as unhealthy.
+fn resolve_failure(error: &Report<ResolveError>) -> HealthCheckResult {
+    HealthCheckResult::unhealthy(format!("Failed to resolve service: {error}"))
+}
@@ -0,0 +1,166 @@
+//! Health check execution.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Run every registered health check and aggregate the results.
+    ///
+    /// - Checks run concurrently, each with its own timeout
+    /// - A service that cannot be resolved or does not respond in time is reported
+    ///   as [`HealthStatus::Unhealthy`]
+    pub async fn check_health(&self) -> HealthReport {
+        let limit = self
+            .registry
+            .health_check_timeout
+            .unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT);
+        let pending = self
+            .registry
+            .health_checks
+            .iter()
+            .map(|registration| self.run_health_check(registration, limit));
+        HealthReport::new(join_all(pending).await)
+    }
+
+    /// Run a single health check with a timeout.
+    async fn run_health_check(
+        &self,
+        registration: &HealthCheckRegistration,
+        limit: Duration,
+    ) -> HealthEntry {
+        let type_name = registration.type_name;
+        trace!(type_name, "Checking service health");
+        let start = Instant::now();
+        let result = timeout(limit, (registration.check)(self.clone()))
+            .await
+            .unwrap_or_else(|_| {
+                HealthCheckResult::unhealthy(format!(
+                    "Health check timed out after {}ms",
+                    limit.as_millis()
+                ))
+            });
+        HealthEntry {
+            type_name,
+            status: result.status,
+            description: result.description,
+            duration: start.elapsed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::sleep;
+
+    #[derive(FromServices)]
+    struct HealthyService;
+
+    impl HealthCheck for HealthyService {
+        fn check_health(&self) -> HealthCheckResult {
+            HealthCheckResult::healthy()
+        }
+    }
+
+    #[derive(FromServices)]
+    struct DegradedService;
+
+    impl HealthCheckAsync for DegradedService {
+        async fn check_health_async(&self) -> HealthCheckResult {
+            HealthCheckResult::degraded("High latency")
+        }
+    }
+
+    #[derive(FromServices)]
+    struct SlowService;
+
+    impl HealthCheckAsync for SlowService {
+        async fn check_health_async(&self) -> HealthCheckResult {
+            sleep(Duration::from_secs(30)).await;
+            HealthCheckResult::healthy()
+        }
+    }
+
+    struct MissingService;
+
+    impl HealthCheck for MissingService {
+        fn check_health(&self) -> HealthCheckResult {
+            HealthCheckResult::healthy()
+        }
+    }
+
+    #[tokio::test]
+    async fn check_health_without_checks_is_healthy() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+
+        // Act
+        let report = services.check_health().await;
+
+        // Assert
+        assert_eq!(report.status, HealthStatus::Healthy);
+        assert!(report.entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn check_health_reports_each_check() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<HealthyService>()
+            .with_health_check::<HealthyService>()
+            .with_type::<DegradedService>()
+            .with_health_check_async::<DegradedService>()
+            .build();
+
+        // Act
+        let report = services.check_health().await;
+
+        // Assert
+        let healthy = report.get::<HealthyService>().expect("should have entry");
+        assert_eq!(healthy.status, HealthStatus::Healthy);
+        let degraded = report.get::<DegradedService>().expect("should have entry");
+        assert_eq!(degraded.status, HealthStatus::Degraded);
+        assert_eq!(degraded.description.as_deref(), Some("High latency"));
+        assert_eq!(report.status, HealthStatus::Degraded);
+    }
+
+    #[tokio::test]
+    async fn check_health_reports_unresolvable_service_as_unhealthy() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_health_check::<MissingService>()
+            .build();
+
+        // Act
+        let report = services.check_health().await;
+
+        // Assert
+        let entry = report.get::<MissingService>().expect("should have entry");
+        assert_eq!(entry.status, HealthStatus::Unhealthy);
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+    }
+
+    #[tokio::test]
+    async fn check_health_reports_timed_out_check_as_unhealthy() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<HealthyService>()
+            .with_health_check::<HealthyService>()
+            .with_type::<SlowService>()
+            .with_health_check_async::<SlowService>()
+            .with_health_check_timeout(Duration::from_millis(20))
+            .build();
+
+        // Act
+        let report = services.check_health().await;
+
+        // Assert
+        let slow = report.get::<SlowService>().expect("should have entry");
+        assert_eq!(slow.status, HealthStatus::Unhealthy);
+        assert!(
+            slow.description
+                .as_deref()
+                .is_some_and(|description| description.contains("timed out"))
+        );
+        let healthy = report.get::<HealthyService>().expect("should have entry");
+        assert_eq!(healthy.status, HealthStatus::Healthy);
+    }
+}
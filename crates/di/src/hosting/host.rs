@@ -0,0 +1,336 @@
+//! Runner for hosted services.
+use crate::prelude::*;
+
+/// Default time allowed for every hosted service to stop.
+const DEFAULT_STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Run the hosted services of a [`ServiceProvider`] until shutdown.
+pub struct Host {
+    services: ServiceProvider,
+    token: ShutdownToken,
+    stop_timeout: Duration,
+    signals: bool,
+}
+
+impl Host {
+    /// Create a [`Host`] for a provider.
+    ///
+    /// The provider is initialized by [`run`](Host::run) if it has not been already.
+    #[must_use]
+    pub fn new(services: ServiceProvider) -> Self {
+        Self {
+            services,
+            token: ShutdownToken::new(),
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            signals: true,
+        }
+    }
+
+    /// Use an existing [`ShutdownToken`] to trigger shutdown.
+    #[must_use]
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Set the time allowed for every hosted service to stop.
+    ///
+    /// Services that have not stopped by the deadline are abandoned and reported
+    /// as [`HostError::Stop`]. Defaults to 30 seconds.
+    #[must_use]
+    pub fn with_stop_timeout(mut self, timeout: Duration) -> Self {
+        self.stop_timeout = timeout;
+        self
+    }
+
+    /// Only shut down when the [`ShutdownToken`] is triggered.
+    ///
+    /// By default SIGINT, and SIGTERM on Unix, also trigger shutdown.
+    #[must_use]
+    pub fn without_signals(mut self) -> Self {
+        self.signals = false;
+        self
+    }
+
+    /// Get the [`ShutdownToken`] that triggers shutdown of this host.
+    #[must_use]
+    pub fn shutdown_token(&self) -> ShutdownToken {
+        self.token.clone()
+    }
+
+    /// Run until shutdown is triggered.
+    ///
+    /// - Initializes the provider via [`ServiceProvider::init_async`] if it is
+    ///   still [`Lifecycle::Built`]
+    /// - Starts hosted services in registration order
+    /// - Waits for a shutdown signal or the [`ShutdownToken`]
+    /// - Stops hosted services in reverse order within the stop timeout
+    /// - Shuts down the provider via [`ServiceProvider::shutdown`]
+    ///
+    /// If a hosted service fails to start, the services already started are stopped
+    /// and an error is returned:
+    ///
+    /// - [`HostError::Resolve`] if the hosted service could not be resolved
+    /// - [`HostError::Start`] if its [`start`](HostedService::start) failed
+    pub async fn run(self) -> Result<(), Report<HostError>> {
+        let services = if self.services.lifecycle() == Lifecycle::Built {
+            self.services
+                .init_async()
+                .await
+                .change_context(HostError::Init)?
+        } else {
+            self.services
+        };
+        let mut stops = Vec::new();
+        for registration in &services.registry.hosted {
+            trace!(
+                type_name = registration.type_name,
+                "Starting hosted service"
+            );
+            match (registration.start)(services.clone()).await {
+                Ok(stop) => stops.push((registration.type_name, stop)),
+                Err(error) => {
+                    let _ = stop_all(stops, self.stop_timeout).await;
+                    let _ = services.shutdown();
+                    let error = match error.current_context() {
+                        HostError::Resolve => error,
+                        _ => error.change_context(HostError::Start),
+                    };
+                    return Err(error.attach("type", registration.type_name));
+                }
+            }
+        }
+        let waited = wait_for_shutdown(&self.token, self.signals).await;
+        let stopped = stop_all(stops, self.stop_timeout).await;
+        let shut_down = services.shutdown().change_context(HostError::Shutdown);
+        waited.and(stopped).and(shut_down)
+    }
+}
+
+/// Wait for the token or, if enabled, a shutdown signal.
+async fn wait_for_shutdown(token: &ShutdownToken, signals: bool) -> Result<(), Report<HostError>> {
+    if !signals {
+        token.triggered().await;
+        return Ok(());
+    }
+    select! {
+        () = token.triggered() => Ok(()),
+        result = wait_for_signal() => result,
+    }
+}
+
+/// Wait for SIGINT, or SIGTERM on Unix.
+#[cfg(unix)]
+async fn wait_for_signal() -> Result<(), Report<HostError>> {
+    let mut terminate = signal(SignalKind::terminate()).change_context(HostError::Signal)?;
+    select! {
+        result = ctrl_c() => result.change_context(HostError::Signal),
+        _ = terminate.recv() => Ok(()),
+    }
+}
+
+/// Wait for SIGINT.
+#[cfg(not(unix))]
+async fn wait_for_signal() -> Result<(), Report<HostError>> {
+    ctrl_c().await.change_context(HostError::Signal)
+}
+
+/// Stop started services in reverse order before a shared deadline.
+///
+/// Every service is stopped even if an earlier one fails or the deadline passes.
+async fn stop_all(
+    stops: Vec<(&'static str, StopFn)>,
+    limit: Duration,
+) -> Result<(), Report<HostError>> {
+    let deadline = Instant::now() + limit;
+    let mut report: Option<Report<HostError>> = None;
+    for (type_name, stop) in stops.into_iter().rev() {
+        trace!(type_name, "Stopping hosted service");
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let failure = match timeout(remaining, stop()).await {
+            Ok(Ok(())) => continue,
+            Ok(Err(error)) => ("failed", format!("{type_name}: {error:?}")),
+            Err(_) => ("timed out", type_name.to_owned()),
+        };
+        let aggregate = report.unwrap_or_else(|| Report::new(HostError::Stop));
+        report = Some(aggregate.attach(failure.0, failure.1));
+    }
+    report.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::spawn;
+    use tokio::time::sleep;
+
+    #[derive(Default)]
+    struct Calls {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    impl Calls {
+        fn push(&self, call: &'static str) {
+            self.calls.lock().expect("should lock").push(call);
+        }
+
+        fn get(&self) -> Vec<&'static str> {
+            self.calls.lock().expect("should lock").clone()
+        }
+    }
+
+    #[derive(FromServices)]
+    struct WorkerA {
+        calls: Arc<Calls>,
+    }
+
+    impl HostedService for WorkerA {
+        async fn start(&self) -> Result<(), Report<HostError>> {
+            self.calls.push("start A");
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<(), Report<HostError>> {
+            self.calls.push("stop A");
+            Ok(())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct WorkerB {
+        calls: Arc<Calls>,
+    }
+
+    impl HostedService for WorkerB {
+        async fn start(&self) -> Result<(), Report<HostError>> {
+            self.calls.push("start B");
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<(), Report<HostError>> {
+            self.calls.push("stop B");
+            Ok(())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct FailingWorker;
+
+    impl HostedService for FailingWorker {
+        async fn start(&self) -> Result<(), Report<HostError>> {
+            Err(Report::new(HostError::Start))
+        }
+
+        async fn stop(&self) -> Result<(), Report<HostError>> {
+            Ok(())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct SlowWorker {
+        calls: Arc<Calls>,
+    }
+
+    impl HostedService for SlowWorker {
+        async fn start(&self) -> Result<(), Report<HostError>> {
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<(), Report<HostError>> {
+            sleep(Duration::from_secs(30)).await;
+            self.calls.push("stop slow");
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn run_starts_in_order_and_stops_in_reverse() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Calls::default())
+            .with_type::<WorkerA>()
+            .with_hosted::<WorkerA>()
+            .with_type::<WorkerB>()
+            .with_hosted::<WorkerB>()
+            .build();
+        let calls = services.expect::<Calls>();
+        let host = Host::new(services.clone()).without_signals();
+        let token = host.shutdown_token();
+
+        // Act
+        let running = spawn(host.run());
+        while calls.get().len() < 2 {
+            sleep(Duration::from_millis(1)).await;
+        }
+        token.trigger();
+        let result = running.await.expect("should join");
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(calls.get(), vec!["start A", "start B", "stop B", "stop A"]);
+        assert_eq!(services.lifecycle(), Lifecycle::Disposed);
+    }
+
+    #[tokio::test]
+    async fn run_stops_started_services_when_start_fails() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Calls::default())
+            .with_type::<WorkerA>()
+            .with_hosted::<WorkerA>()
+            .with_type::<FailingWorker>()
+            .with_hosted::<FailingWorker>()
+            .with_type::<WorkerB>()
+            .with_hosted::<WorkerB>()
+            .build();
+        let calls = services.expect::<Calls>();
+
+        // Act
+        let result = Host::new(services).without_signals().run().await;
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &HostError::Start);
+        assert_eq!(calls.get(), vec!["start A", "stop A"]);
+    }
+
+    #[tokio::test]
+    async fn run_reports_unresolvable_hosted_service() {
+        // Arrange
+        let services = ServiceBuilder::new().with_hosted::<WorkerA>().build();
+
+        // Act
+        let result = Host::new(services).without_signals().run().await;
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &HostError::Resolve);
+    }
+
+    #[tokio::test]
+    async fn run_abandons_services_that_miss_the_stop_deadline() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Calls::default())
+            .with_type::<WorkerA>()
+            .with_hosted::<WorkerA>()
+            .with_type::<SlowWorker>()
+            .with_hosted::<SlowWorker>()
+            .build();
+        let calls = services.expect::<Calls>();
+        let token = ShutdownToken::new();
+        token.trigger();
+        let host = Host::new(services)
+            .with_shutdown_token(token)
+            .with_stop_timeout(Duration::from_millis(20))
+            .without_signals();
+
+        // Act
+        let result = host.run().await;
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &HostError::Stop);
+        assert_eq!(calls.get(), vec!["start A", "stop A"]);
+    }
+}
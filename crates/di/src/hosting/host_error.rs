@@ -0,0 +1,25 @@
+//! Errors returned by the [`Host`] runner.
+use crate::prelude::*;
+
+/// Errors returned by [`HostedService`] implementations and the [`Host`] runner.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum HostError {
+    /// Initialization of the provider failed.
+    #[error("Failed to initialize services")]
+    Init,
+    /// A hosted service could not be resolved.
+    #[error("Failed to resolve hosted service")]
+    Resolve,
+    /// A hosted service failed to start.
+    #[error("Hosted service failed to start")]
+    Start,
+    /// One or more hosted services failed to stop before the deadline.
+    #[error("Hosted service failed to stop")]
+    Stop,
+    /// Listening for shutdown signals failed.
+    #[error("Failed to listen for shutdown signals")]
+    Signal,
+    /// Shutting down the provider failed.
+    #[error("Failed to shut down services")]
+    Shutdown,
+}
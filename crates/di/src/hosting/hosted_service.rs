@@ -0,0 +1,18 @@
+//! Hosted service trait.
+use crate::prelude::*;
+
+/// Long-running background work started and stopped by a [`Host`].
+///
+/// Registered via [`ServiceBuilder::with_hosted`].
+pub trait HostedService: Send + Sync + 'static {
+    /// Start the service.
+    ///
+    /// Called once after initialization. Should return once the service has
+    /// started, spawning any long-running work rather than awaiting it.
+    fn start(&self) -> impl Future<Output = Result<(), Report<HostError>>> + Send;
+
+    /// Stop the service.
+    ///
+    /// Called once when the host shuts down, in reverse start order.
+    fn stop(&self) -> impl Future<Output = Result<(), Report<HostError>>> + Send;
+}
@@ -0,0 +1,12 @@
+//! Hosted background services.
+mod host;
+mod host_error;
+mod hosted_service;
+mod service_builder_hosting;
+mod shutdown_token;
+
+pub use host::*;
+pub use host_error::*;
+pub use hosted_service::*;
+pub(crate) use service_builder_hosting::*;
+pub use shutdown_token::*;
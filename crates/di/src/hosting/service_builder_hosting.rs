@@ -0,0 +1,49 @@
+//! Hosted service registration.
+use crate::prelude::*;
+
+/// Closure that stops a started hosted service.
+pub(crate) type StopFn = Box<dyn FnOnce() -> BoxFuture<Result<(), Report<HostError>>> + Send>;
+
+/// Closure that resolves and starts a hosted service, returning its stop closure.
+pub(crate) type StartFn =
+    Arc<dyn Fn(ServiceProvider) -> BoxFuture<Result<StopFn, Report<HostError>>> + Send + Sync>;
+
+/// Internal hosted service entry pairing a service type with its start closure.
+#[derive(Clone)]
+pub(crate) struct HostedRegistration {
+    /// Hosted service type.
+    pub type_id: TypeId,
+    /// Name of the hosted service type.
+    pub type_name: &'static str,
+    /// Closure that resolves and starts the service.
+    pub start: StartFn,
+}
+
+impl ServiceBuilder {
+    /// Mark a type to be started and stopped by a [`Host`].
+    ///
+    /// Hosted services start in registration order and stop in reverse order.
+    /// The service is resolved via [`get_async`](ServiceProvider::get_async) so it
+    /// must also be registered with [`with_type`](ServiceBuilder::with_type) or
+    /// [`with_type_async`](ServiceBuilder::with_type_async).
+    #[must_use]
+    pub fn with_hosted<T: HostedService>(mut self) -> Self {
+        let start: StartFn = Arc::new(|services: ServiceProvider| {
+            Box::pin(async move {
+                let instance = services
+                    .get_async::<T>()
+                    .await
+                    .change_context(HostError::Resolve)?;
+                instance.start().await?;
+                let stop: StopFn = Box::new(move || Box::pin(async move { instance.stop().await }));
+                Ok(stop)
+            })
+        });
+        self.hosted.push(HostedRegistration {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            start,
+        });
+        self
+    }
+}
@@ -0,0 +1,48 @@
+//! Programmatic shutdown trigger.
+use crate::prelude::*;
+
+/// Cloneable handle that triggers the shutdown of a [`Host`].
+///
+/// Every clone shares the same state, so the token can be registered as an
+/// instance and triggered from within a service.
+#[derive(Clone, Debug)]
+pub struct ShutdownToken {
+    sender: Arc<WatchSender<bool>>,
+}
+
+impl ShutdownToken {
+    /// Create an untriggered [`ShutdownToken`].
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, _) = watch_channel(false);
+        Self {
+            sender: Arc::new(sender),
+        }
+    }
+
+    /// Trigger shutdown.
+    ///
+    /// Calling more than once has no effect.
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// Check whether shutdown has been triggered.
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Wait until shutdown is triggered.
+    pub async fn triggered(&self) {
+        let mut receiver = self.sender.subscribe();
+        // The sender is owned by `self` so the channel cannot close while waiting.
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+}
+
+impl Default for ShutdownToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
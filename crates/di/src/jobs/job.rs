@@ -0,0 +1,26 @@
+//! Job trait.
+use crate::prelude::*;
+
+/// Work run by the [`Scheduler`] on a [`Schedule`].
+///
+/// Registered via [`ServiceBuilder::with_job`]. A fresh instance is resolved
+/// for every run, and each run is installed as a [`ServiceScope`] so
+/// [`ServiceProvider::current`] returns the provider of the run.
+pub trait Job: Send + Sync + 'static {
+    /// Run the job once.
+    fn run(&self) -> impl Future<Output = Result<(), Report<JobError>>> + Send;
+}
+
+/// Errors returned by [`Job`] implementations and [`Schedule`] parsing.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum JobError {
+    /// The job failed.
+    #[error("Job failed")]
+    Run,
+    /// A fresh job instance could not be resolved.
+    #[error("Failed to resolve job")]
+    Resolve,
+    /// The interval period is zero or the cron expression could not be parsed.
+    #[error("Invalid schedule")]
+    Schedule,
+}
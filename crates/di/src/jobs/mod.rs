@@ -0,0 +1,10 @@
+//! Scheduled jobs.
+mod job;
+mod schedule;
+mod scheduler;
+mod service_builder_jobs;
+
+pub use job::*;
+pub use schedule::*;
+pub use scheduler::*;
+pub(crate) use service_builder_jobs::*;
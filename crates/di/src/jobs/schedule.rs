@@ -0,0 +1,213 @@
+//! Job schedules.
+use crate::prelude::*;
+
+/// When a [`Job`] runs.
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    trigger: Trigger,
+    jitter: Duration,
+    overlap: bool,
+}
+
+/// Source of the run times of a [`Schedule`].
+#[derive(Clone, Debug)]
+enum Trigger {
+    /// Run at a fixed interval after the scheduler starts.
+    Interval(Duration),
+    /// Run at the times matched by a cron expression, in UTC.
+    Cron(Box<CronSchedule>),
+}
+
+impl Schedule {
+    /// Run every `period`, starting one period after the scheduler starts.
+    ///
+    /// Returns [`JobError::Schedule`] if `period` is zero.
+    pub fn interval(period: Duration) -> Result<Self, Report<JobError>> {
+        if period.is_zero() {
+            return Err(Report::new(JobError::Schedule).attach("period", format!("{period:?}")));
+        }
+        Ok(Self::new(Trigger::Interval(period)))
+    }
+
+    /// Run at the times matched by a cron expression, evaluated in UTC.
+    ///
+    /// The expression includes a leading seconds field, for example
+    /// `0 */5 * * * *` runs every five minutes.
+    pub fn cron(expression: &str) -> Result<Self, Report<JobError>> {
+        let schedule = CronSchedule::from_str(expression)
+            .change_context(JobError::Schedule)
+            .attach("expression", expression.to_owned())?;
+        Ok(Self::new(Trigger::Cron(Box::new(schedule))))
+    }
+
+    /// Delay each run by a random duration of up to `max`.
+    ///
+    /// Spreads the load when many instances share a schedule. Jitter does not
+    /// accumulate: each run is delayed relative to its scheduled time.
+    #[must_use]
+    pub fn with_jitter(mut self, max: Duration) -> Self {
+        self.jitter = max;
+        self
+    }
+
+    /// Allow a run to start while the previous run is still in progress.
+    ///
+    /// By default a run is skipped if the previous run has not finished.
+    #[must_use]
+    pub fn with_overlap(mut self) -> Self {
+        self.overlap = true;
+        self
+    }
+
+    fn new(trigger: Trigger) -> Self {
+        Self {
+            trigger,
+            jitter: Duration::ZERO,
+            overlap: false,
+        }
+    }
+
+    /// Whether overlapping runs are allowed.
+    pub(crate) fn overlap(&self) -> bool {
+        self.overlap
+    }
+
+    /// Get the scheduled time of the run after `previous`.
+    ///
+    /// Returns `None` if the schedule has no further runs.
+    pub(crate) fn next(
+        &self,
+        previous: TokioInstant,
+        clock: &ScheduleClock,
+    ) -> Option<TokioInstant> {
+        match &self.trigger {
+            Trigger::Interval(period) => Some(previous + *period),
+            Trigger::Cron(schedule) => {
+                let from = clock.utc(previous);
+                let upcoming = schedule.after(&from).next()?;
+                let delay = (upcoming - from).to_std().unwrap_or_default();
+                Some(previous + delay)
+            }
+        }
+    }
+
+    /// Get a random delay of up to the configured jitter.
+    pub(crate) fn jitter(&self) -> Duration {
+        if self.jitter.is_zero() {
+            return Duration::ZERO;
+        }
+        let max = u64::try_from(self.jitter.as_nanos()).unwrap_or(u64::MAX);
+        Duration::from_nanos(fastrand::u64(..=max))
+    }
+}
+
+/// Mapping from the tokio clock to UTC used to evaluate cron schedules.
+///
+/// Cron run times are derived from the tokio clock rather than the system
+/// clock so a paused tokio clock drives them.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScheduleClock {
+    instant: TokioInstant,
+    utc: DateTime<Utc>,
+}
+
+impl ScheduleClock {
+    /// Create a clock anchored at the current time.
+    pub(crate) fn now() -> Self {
+        Self::at(Utc::now())
+    }
+
+    /// Create a clock where the current tokio instant is `utc`.
+    pub(crate) fn at(utc: DateTime<Utc>) -> Self {
+        Self {
+            instant: TokioInstant::now(),
+            utc,
+        }
+    }
+
+    /// Get the UTC time of a tokio instant.
+    fn utc(&self, instant: TokioInstant) -> DateTime<Utc> {
+        let elapsed = instant.saturating_duration_since(self.instant);
+        self.utc + TimeDelta::from_std(elapsed).unwrap_or(TimeDelta::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cron_parses_valid_expression() {
+        // Arrange
+        let expression = "0 */5 * * * *";
+
+        // Act
+        let result = Schedule::cron(expression);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn cron_rejects_invalid_expression() {
+        // Arrange
+        let expression = "every five minutes";
+
+        // Act
+        let result = Schedule::cron(expression);
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &JobError::Schedule);
+    }
+
+    #[test]
+    fn interval_rejects_zero_period() {
+        // Arrange
+        let period = Duration::ZERO;
+
+        // Act
+        let result = Schedule::interval(period);
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &JobError::Schedule);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cron_next_follows_tokio_clock() {
+        // Arrange
+        let schedule = Schedule::cron("0 * * * * *").expect("should parse");
+        let utc = DateTime::parse_from_rfc3339("2026-01-01T00:00:30Z")
+            .expect("should parse")
+            .to_utc();
+        let clock = ScheduleClock::at(utc);
+        let start = TokioInstant::now();
+
+        // Act
+        let first = schedule.next(start, &clock).expect("should have next");
+        let second = schedule.next(first, &clock).expect("should have next");
+
+        // Assert
+        assert_eq!(first - start, Duration::from_secs(30));
+        assert_eq!(second - start, Duration::from_secs(90));
+    }
+
+    #[test]
+    fn jitter_is_within_bounds() {
+        // Arrange
+        let schedule = Schedule::interval(Duration::from_secs(1))
+            .expect("should be valid")
+            .with_jitter(Duration::from_millis(10));
+
+        // Act
+        let delays: Vec<Duration> = (0..100).map(|_| schedule.jitter()).collect();
+
+        // Assert
+        assert!(
+            delays
+                .iter()
+                .all(|delay| *delay <= Duration::from_millis(10))
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Runner for scheduled jobs.
+use crate::prelude::*;
+
+/// Run registered jobs on their [`Schedule`].
+///
+/// Registered as a [`HostedService`] by [`ServiceBuilder::with_job`] so it is
+/// started and stopped by the [`Host`].
+pub struct Scheduler {
+    services: ServiceProvider,
+    stop: ShutdownToken,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl FromServices for Scheduler {
+    type Error = ResolveError;
+
+    fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        Ok(Self {
+            services: services.clone(),
+            stop: ShutdownToken::new(),
+            tasks: Mutex::new(Vec::new()),
+        })
+    }
+}
+
+impl HostedService for Scheduler {
+    /// Spawn a task for each registered job.
+    async fn start(&self) -> Result<(), Report<HostError>> {
+        let mut tasks = self.tasks.lock().expect("should be able to lock tasks");
+        let clock = ScheduleClock::now();
+        for index in 0..self.services.registry.jobs.len() {
+            let services = self.services.clone();
+            let stop = self.stop.clone();
+            tasks.push(spawn(run_schedule(services, index, stop, clock)));
+        }
+        Ok(())
+    }
+
+    /// Stop scheduling and wait for runs in progress to finish.
+    async fn stop(&self) -> Result<(), Report<HostError>> {
+        self.stop.trigger();
+        let tasks: Vec<JoinHandle<()>> = self
+            .tasks
+            .lock()
+            .expect("should be able to lock tasks")
+            .drain(..)
+            .collect();
+        for task in tasks {
+            task.await.change_context(HostError::Stop)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run a single job on its schedule until stopped.
+async fn run_schedule(
+    services: ServiceProvider,
+    index: usize,
+    stop: ShutdownToken,
+    clock: ScheduleClock,
+) {
+    let Some(registration) = services.registry.jobs.get(index) else {
+        return;
+    };
+    let schedule = &registration.schedule;
+    let mut overlapping = JoinSet::new();
+    let mut run: u64 = 0;
+    let mut next = schedule.next(TokioInstant::now(), &clock);
+    while let Some(scheduled) = next {
+        select! {
+            () = stop.triggered() => break,
+            () = sleep_until(scheduled + schedule.jitter()) => {}
+        }
+        run += 1;
+        if schedule.overlap() {
+            overlapping.spawn(run_job(services.clone(), index, run));
+            next = schedule.next(scheduled, &clock);
+            continue;
+        }
+        run_job(services.clone(), index, run).await;
+        next = schedule.next(scheduled, &clock);
+        while let Some(missed) = next.filter(|missed| *missed < TokioInstant::now()) {
+            trace!(
+                job = registration.type_name,
+                "Skipping run because the previous run is still in progress"
+            );
+            next = schedule.next(missed, &clock);
+        }
+    }
+    while overlapping.join_next().await.is_some() {}
+}
+
+/// Resolve and run a job once within its own scope and tracing span.
+async fn run_job(services: ServiceProvider, index: usize, run: u64) {
+    let Some(registration) = services.registry.jobs.get(index) else {
+        return;
+    };
+    let span = info_span!("job", job = registration.type_name, run);
+    let scope = services.scope();
+    let job = async {
+        trace!("Running job");
+        let start = Instant::now();
+        match (registration.run)(ServiceProvider::clone(&scope)).await {
+            Ok(()) => trace!(elapsed = ?start.elapsed(), "Job completed"),
+            Err(error) => warn!(elapsed = ?start.elapsed(), ?error, "Job failed"),
+        }
+    };
+    scope.run(job).instrument(span).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::time::sleep;
+
+    #[derive(Default)]
+    struct Runs {
+        constructed: AtomicUsize,
+        started: AtomicUsize,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+        scopes: Mutex<Vec<Weak<ServiceRegistry>>>,
+    }
+
+    struct CountingJob {
+        runs: Arc<Runs>,
+    }
+
+    impl FromServicesAsync for CountingJob {
+        type Error = ResolveError;
+
+        async fn from_services_async(
+            services: &ServiceProvider,
+        ) -> Result<Self, Report<ResolveError>> {
+            let runs = services.get::<Runs>()?;
+            runs.constructed.fetch_add(1, Ordering::SeqCst);
+            Ok(Self { runs })
+        }
+    }
+
+    impl Job for CountingJob {
+        async fn run(&self) -> Result<(), Report<JobError>> {
+            self.runs.started.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[derive(FromServicesAsync)]
+    struct SlowJob {
+        runs: Arc<Runs>,
+    }
+
+    impl Job for SlowJob {
+        async fn run(&self) -> Result<(), Report<JobError>> {
+            self.runs.started.fetch_add(1, Ordering::SeqCst);
+            let running = self.runs.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.runs.max_running.fetch_max(running, Ordering::SeqCst);
+            sleep(Duration::from_secs(25)).await;
+            self.runs.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[derive(FromServicesAsync)]
+    struct ScopedJob {
+        runs: Arc<Runs>,
+    }
+
+    impl Job for ScopedJob {
+        async fn run(&self) -> Result<(), Report<JobError>> {
+            let current = ServiceProvider::current().ok_or(JobError::Run)?;
+            let scope = Arc::downgrade(&current.registry);
+            self.runs.scopes.lock().expect("should lock").push(scope);
+            Ok(())
+        }
+    }
+
+    fn interval(period: Duration) -> Schedule {
+        Schedule::interval(period).expect("should be valid")
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_runs_fresh_instance_on_interval() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<CountingJob>(interval(Duration::from_secs(10)))
+            .build();
+        let runs = services.expect::<Runs>();
+        let scheduler = services.expect::<Scheduler>();
+
+        // Act
+        scheduler.start().await.expect("should start");
+        sleep(Duration::from_secs(35)).await;
+        scheduler.stop().await.expect("should stop");
+
+        // Assert
+        assert_eq!(runs.started.load(Ordering::SeqCst), 3);
+        assert_eq!(runs.constructed.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_skips_runs_that_would_overlap() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<SlowJob>(interval(Duration::from_secs(10)))
+            .build();
+        let runs = services.expect::<Runs>();
+        let scheduler = services.expect::<Scheduler>();
+
+        // Act
+        scheduler.start().await.expect("should start");
+        sleep(Duration::from_secs(55)).await;
+        scheduler.stop().await.expect("should stop");
+
+        // Assert
+        assert_eq!(runs.started.load(Ordering::SeqCst), 2);
+        assert_eq!(runs.max_running.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_allows_overlap_when_enabled() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<SlowJob>(interval(Duration::from_secs(10)).with_overlap())
+            .build();
+        let runs = services.expect::<Runs>();
+        let scheduler = services.expect::<Scheduler>();
+
+        // Act
+        scheduler.start().await.expect("should start");
+        sleep(Duration::from_secs(55)).await;
+        scheduler.stop().await.expect("should stop");
+
+        // Assert
+        assert_eq!(runs.started.load(Ordering::SeqCst), 5);
+        assert_eq!(runs.max_running.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_runs_cron_schedule_on_tokio_clock() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<CountingJob>(Schedule::cron("0 * * * * *").expect("should parse"))
+            .build();
+        let runs = services.expect::<Runs>();
+        let utc = DateTime::parse_from_rfc3339("2026-01-01T00:00:30Z")
+            .expect("should parse")
+            .to_utc();
+        let stop = ShutdownToken::new();
+        let task = spawn(run_schedule(
+            services.clone(),
+            0,
+            stop.clone(),
+            ScheduleClock::at(utc),
+        ));
+
+        // Act
+        sleep(Duration::from_secs(140)).await;
+        stop.trigger();
+        task.await.expect("should join");
+
+        // Assert
+        assert_eq!(runs.started.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_stops_waiting_for_next_run() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<CountingJob>(interval(Duration::from_hours(1)))
+            .build();
+        let runs = services.expect::<Runs>();
+        let scheduler = services.expect::<Scheduler>();
+        scheduler.start().await.expect("should start");
+
+        // Act
+        let result = scheduler.stop().await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(runs.started.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduler_runs_each_job_in_own_scope() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Runs::default())
+            .with_job::<ScopedJob>(interval(Duration::from_secs(10)))
+            .build();
+        let runs = services.expect::<Runs>();
+        let scheduler = services.expect::<Scheduler>();
+
+        // Act
+        scheduler.start().await.expect("should start");
+        sleep(Duration::from_secs(25)).await;
+        scheduler.stop().await.expect("should stop");
+
+        // Assert
+        let scopes = runs.scopes.lock().expect("should lock").clone();
+        let root = Arc::downgrade(&services.registry);
+        let [first, second] = scopes.as_slice() else {
+            unreachable!("should run twice");
+        };
+        assert!(!first.ptr_eq(second));
+        assert!(!first.ptr_eq(&root) && !second.ptr_eq(&root));
+    }
+}
@@ -0,0 +1,46 @@
+//! Job registration.
+use crate::prelude::*;
+
+/// Closure that resolves a fresh job instance and runs it.
+pub(crate) type JobFn =
+    Arc<dyn Fn(ServiceProvider) -> BoxFuture<Result<(), Report<JobError>>> + Send + Sync>;
+
+/// Internal job entry pairing a job type with its schedule.
+#[derive(Clone)]
+pub(crate) struct JobRegistration {
+    /// Name of the job type.
+    pub type_name: &'static str,
+    /// When the job runs.
+    pub schedule: Schedule,
+    /// Closure that resolves and runs the job.
+    pub run: JobFn,
+}
+
+impl ServiceBuilder {
+    /// Register a job to be run by the [`Scheduler`] on a [`Schedule`].
+    ///
+    /// - The job type is registered as transient so every run gets a fresh instance
+    /// - Each run gets its own [`ServiceScope`]
+    /// - The [`Scheduler`] is registered as a [`HostedService`] on the first call
+    #[must_use]
+    pub fn with_job<T: Job + FromServicesAsync>(mut self, schedule: Schedule) -> Self {
+        if self.jobs.is_empty() {
+            self = self.with_type::<Scheduler>().with_hosted::<Scheduler>();
+        }
+        let run: JobFn = Arc::new(|services: ServiceProvider| {
+            Box::pin(async move {
+                let instance = services
+                    .get_async::<T>()
+                    .await
+                    .change_context(JobError::Resolve)?;
+                instance.run().await
+            })
+        });
+        self.jobs.push(JobRegistration {
+            type_name: type_name::<T>(),
+            schedule,
+            run,
+        });
+        self.register_type_async::<T>(TransientLifetime)
+    }
+}
@@ -0,0 +1,130 @@
+//! Least recently used cache of keyed instances.
+use crate::prelude::*;
+
+/// Cache of the instances of one keyed registration.
+pub(crate) struct KeyedCache<K, T> {
+    /// Cached instances with the tick they were last used.
+    entries: HashMap<K, (Arc<T>, u64)>,
+    /// Keys ordered by the tick they were last used.
+    order: BTreeMap<u64, K>,
+    /// Incremented on every use.
+    tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, T> KeyedCache<K, T> {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Get a cached instance and mark it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<Arc<T>> {
+        self.tick += 1;
+        let (value, used) = self.entries.get_mut(key)?;
+        self.order.remove(used);
+        self.order.insert(self.tick, key.clone());
+        *used = self.tick;
+        Some(value.clone())
+    }
+
+    /// Cache an instance unless one was cached for the key by a concurrent call.
+    ///
+    /// Returns the cached instance and the instances evicted to stay within
+    /// `capacity`.
+    pub fn insert(
+        &mut self,
+        key: K,
+        value: Arc<T>,
+        capacity: Option<usize>,
+    ) -> (Arc<T>, Vec<Arc<T>>) {
+        if let Some(existing) = self.get(&key) {
+            return (existing, Vec::new());
+        }
+        let mut evicted = Vec::new();
+        if let Some(capacity) = capacity {
+            while self.entries.len() >= capacity.max(1) {
+                let Some((_, oldest)) = self.order.pop_first() else {
+                    break;
+                };
+                if let Some((value, _)) = self.entries.remove(&oldest) {
+                    evicted.push(value);
+                }
+            }
+        }
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value.clone(), self.tick));
+        (value, evicted)
+    }
+}
+
+/// Type-erased [`KeyedCache`] stored by the registry.
+pub(crate) trait ErasedKeyedCache: Send {
+    /// Remove and return every cached instance.
+    fn drain(&mut self) -> Vec<Arc<dyn Any + Send + Sync>>;
+
+    /// Get the cache as [`Any`] to downcast to the concrete [`KeyedCache`].
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<K, T> ErasedKeyedCache for KeyedCache<K, T>
+where
+    K: Hash + Eq + Clone + Send + 'static,
+    T: Send + Sync + 'static,
+{
+    fn drain(&mut self) -> Vec<Arc<dyn Any + Send + Sync>> {
+        self.order.clear();
+        self.entries
+            .drain()
+            .map(|(_, (value, _))| {
+                let dynamic: Arc<dyn Any + Send + Sync> = value;
+                dynamic
+            })
+            .collect()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_evicts_least_recently_used() {
+        // Arrange
+        let mut cache = KeyedCache::new();
+        cache.insert("a", Arc::new(1), Some(2));
+        cache.insert("b", Arc::new(2), Some(2));
+        let _a = cache.get(&"a");
+
+        // Act
+        let (_, evicted) = cache.insert("c", Arc::new(3), Some(2));
+
+        // Assert
+        assert_eq!(evicted, vec![Arc::new(2)]);
+        assert!(cache.get(&"a").is_some());
+        assert!(cache.get(&"b").is_none());
+        assert!(cache.get(&"c").is_some());
+    }
+
+    #[test]
+    fn insert_keeps_existing() {
+        // Arrange
+        let mut cache = KeyedCache::new();
+        let (first, _) = cache.insert("a", Arc::new(1), None);
+
+        // Act
+        let (second, evicted) = cache.insert("a", Arc::new(2), None);
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(evicted.is_empty());
+    }
+}
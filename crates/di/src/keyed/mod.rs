@@ -0,0 +1,6 @@
+//! Services cached per argument value.
+mod keyed_cache;
+mod service_builder_keyed;
+mod service_provider_keyed;
+
+pub(crate) use keyed_cache::*;
@@ -0,0 +1,47 @@
+//! Keyed service registration.
+use crate::prelude::*;
+
+impl ServiceBuilder {
+    /// Register a type constructed once per distinct argument value.
+    ///
+    /// Resolve with [`ServiceProvider::get_for`]. Instances are constructed via
+    /// [`FromServicesWith`] with the key as the only argument and cached until the
+    /// provider shuts down.
+    #[must_use]
+    pub fn with_keyed<T, K>(self) -> Self
+    where
+        T: FromServicesWith<(K,)>,
+        K: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        self.register_keyed::<T, K>(None)
+    }
+
+    /// Register a type constructed once per distinct argument value, keeping at most
+    /// `capacity` instances.
+    ///
+    /// When the cache is full the least recently used instance is evicted and
+    /// disposed if `T` was registered with [`with_dispose`](ServiceBuilder::with_dispose).
+    /// A capacity of zero is treated as one.
+    #[must_use]
+    pub fn with_keyed_lru<T, K>(self, capacity: usize) -> Self
+    where
+        T: FromServicesWith<(K,)>,
+        K: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        self.register_keyed::<T, K>(Some(capacity))
+    }
+
+    /// Register a keyed type with an optional capacity.
+    fn register_keyed<T: 'static, K: 'static>(mut self, capacity: Option<usize>) -> Self {
+        self.keyed.insert(
+            TypeId::of::<T>(),
+            KeyedRegistration {
+                type_name: type_name::<T>(),
+                key_type_id: TypeId::of::<K>(),
+                capacity,
+                profile: None,
+            },
+        );
+        self
+    }
+}
@@ -0,0 +1,245 @@
+//! Keyed service resolution.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Resolve the instance of a keyed type for an argument value.
+    ///
+    /// The instance is constructed on the first call for each distinct key and
+    /// cached, subject to the capacity set by
+    /// [`with_keyed_lru`](ServiceBuilder::with_keyed_lru).
+    ///
+    /// Returns [`ResolveError::NotFound`] if the type is not registered with
+    /// [`with_keyed`](ServiceBuilder::with_keyed) for keys of type `K`.
+    ///
+    /// ```ignore
+    /// let database = services.get_for::<TenantDatabase, _>(&tenant_id)?;
+    /// ```
+    pub fn get_for<T, K>(&self, key: &K) -> Result<Arc<T>, Report<ResolveError>>
+    where
+        T: FromServicesWith<(K,)>,
+        K: Hash + Eq + Clone + Send + Sync + 'static,
+    {
+        let key_name = type_name::<K>();
+        let type_name = type_name::<T>();
+        trace!(type_name, "Resolving keyed service");
+        let type_id = TypeId::of::<T>();
+        self.check_lifecycle(type_id, type_name)?;
+        let Some(registration) = self.registry.keyed.get(&type_id) else {
+            return self.parent(type_name)?.get_for::<T, K>(key);
+        };
+        if registration.key_type_id != TypeId::of::<K>() {
+            return Err(Report::new(ResolveError::NotFound))
+                .attach("type", type_name)
+                .attach("key", key_name);
+        }
+        if let Some(value) = self.with_keyed_cache::<T, K, _>(|cache| cache.get(key)) {
+            return Ok(value);
+        }
+        let value = T::from_services_with(self, (key.clone(),))
+            .change_context(ResolveError::Factory)
+            .attach("type", type_name)?;
+        let (value, evicted) = self.with_keyed_cache::<T, K, _>(|cache| {
+            cache.insert(key.clone(), Arc::new(value), registration.capacity)
+        });
+        for instance in evicted {
+            trace!(type_name, "Evicting keyed service");
+            self.dispose_instance(type_id, instance);
+        }
+        Ok(value)
+    }
+
+    /// Run `action` with the cache of a keyed type, creating it if necessary.
+    fn with_keyed_cache<T, K, R>(&self, action: impl FnOnce(&mut KeyedCache<K, T>) -> R) -> R
+    where
+        T: Send + Sync + 'static,
+        K: Hash + Eq + Clone + Send + 'static,
+    {
+        let mut caches = self
+            .registry
+            .keyed_instances
+            .lock()
+            .expect("should be able to lock keyed instances");
+        let cache = caches
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(KeyedCache::<K, T>::new()));
+        let cache = cache
+            .as_any_mut()
+            .downcast_mut::<KeyedCache<K, T>>()
+            .expect("keyed cache should match its registration");
+        action(cache)
+    }
+
+    /// Drop every cached keyed instance, disposing those with a disposal hook.
+    pub(crate) fn dispose_keyed(&self) {
+        let caches = take(
+            &mut *self
+                .registry
+                .keyed_instances
+                .lock()
+                .expect("should be able to lock keyed instances"),
+        );
+        for (type_id, mut cache) in caches {
+            for instance in cache.drain() {
+                self.dispose_instance(type_id, instance);
+            }
+        }
+    }
+
+    /// Dispose an instance if its type has a disposal hook.
+    fn dispose_instance(&self, type_id: TypeId, instance: Arc<dyn Any + Send + Sync>) {
+        if let Some(dispose) = self.registry.disposers.get(&type_id) {
+            dispose(instance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Record of disposed tenants.
+    #[derive(Default)]
+    struct DisposeLog {
+        tenants: Mutex<Vec<String>>,
+    }
+
+    impl DisposeLog {
+        fn tenants(&self) -> Vec<String> {
+            self.tenants.lock().expect("should lock").clone()
+        }
+    }
+
+    /// Service constructed once per tenant.
+    #[derive(FromServices)]
+    struct TenantDatabase {
+        log: Arc<DisposeLog>,
+        #[di(arg)]
+        tenant: String,
+    }
+
+    impl Dispose for TenantDatabase {
+        fn dispose(&self) {
+            self.log
+                .tenants
+                .lock()
+                .expect("should lock")
+                .push(self.tenant.clone());
+        }
+    }
+
+    fn builder() -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_instance(DisposeLog::default())
+            .with_dispose::<TenantDatabase>()
+    }
+
+    fn get(services: &ServiceProvider, tenant: &str) -> Arc<TenantDatabase> {
+        services
+            .get_for::<TenantDatabase, _>(&tenant.to_owned())
+            .expect("should resolve")
+    }
+
+    #[test]
+    fn same_key_returns_same_instance() {
+        // Arrange
+        let services = builder().with_keyed::<TenantDatabase, String>().build();
+
+        // Act
+        let first = get(&services, "acme");
+        let second = get(&services, "acme");
+        let other = get(&services, "globex");
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(other.tenant, "globex");
+    }
+
+    #[test]
+    fn lru_evicts_and_disposes_least_recently_used() {
+        // Arrange
+        let services = builder()
+            .with_keyed_lru::<TenantDatabase, String>(2)
+            .build();
+        let acme = get(&services, "acme");
+        let _globex = get(&services, "globex");
+        let _acme = get(&services, "acme");
+
+        // Act
+        let _initech = get(&services, "initech");
+
+        // Assert
+        let log = services.expect::<DisposeLog>();
+        assert_eq!(log.tenants(), ["globex"]);
+        assert!(Arc::ptr_eq(&acme, &get(&services, "acme")));
+    }
+
+    #[test]
+    fn shutdown_disposes_cached_instances() {
+        // Arrange
+        let services = builder().with_keyed::<TenantDatabase, String>().build();
+        let log = services.expect::<DisposeLog>();
+        let _acme = get(&services, "acme");
+
+        // Act
+        services.shutdown().expect("should shut down");
+
+        // Assert
+        assert_eq!(log.tenants(), ["acme"]);
+    }
+
+    #[test]
+    fn unregistered_type_returns_not_found() {
+        // Arrange
+        let services = builder().build();
+
+        // Act
+        let result = services.get_for::<TenantDatabase, _>(&String::from("acme"));
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn child_shares_parent_cache() {
+        // Arrange
+        let parent = builder().with_keyed::<TenantDatabase, String>().build();
+        let child = parent.child(|builder| builder);
+
+        // Act
+        let from_child = get(&child, "acme");
+
+        // Assert
+        assert!(Arc::ptr_eq(&from_child, &get(&parent, "acme")));
+    }
+
+    #[test]
+    fn fork_has_own_cache() {
+        // Arrange
+        let services = builder().with_keyed::<TenantDatabase, String>().build();
+        let fork = services.fork();
+
+        // Act
+        let from_fork = get(&fork, "acme");
+
+        // Assert
+        assert!(!Arc::ptr_eq(&from_fork, &get(&services, "acme")));
+    }
+
+    #[test]
+    fn descriptors_report_keyed() {
+        // Arrange
+        let services = builder().with_keyed::<TenantDatabase, String>().build();
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let descriptor = descriptors
+            .iter()
+            .find(|descriptor| descriptor.type_name.ends_with("::TenantDatabase"))
+            .expect("should describe keyed service");
+        assert_eq!(descriptor.lifetime, ServiceLifetime::Keyed);
+    }
+}
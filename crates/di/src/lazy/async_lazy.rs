@@ -0,0 +1,125 @@
+//! Service resolved asynchronously on first use.
+use crate::prelude::*;
+
+/// Function that resolves the service of an [`AsyncLazy`].
+type AsyncLazyResolveFn<T> = fn(ServiceProvider) -> BoxFuture<Result<Arc<T>, Report<ResolveError>>>;
+
+/// Service resolved asynchronously on first use.
+///
+/// The async counterpart of [`Lazy`] for services registered with `with_type_async`
+/// or `with_trait_async`. Resolve with [`ServiceProvider::get_lazy_async`], or
+/// `get_lazy_trait_async` for a trait object, or declare an `AsyncLazy<T>` field
+/// on a struct deriving [`FromServices`] or [`FromServicesAsync`].
+///
+/// Concurrent first calls may each resolve the service. Only the first result is
+/// kept, so this only matters for transients.
+pub struct AsyncLazy<T: ?Sized> {
+    provider: Weak<ServiceRegistry>,
+    resolver: AsyncLazyResolveFn<T>,
+    value: OnceLock<Arc<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> AsyncLazy<T> {
+    /// Create a handle that resolves with `resolver` on first use.
+    pub(crate) fn new(services: &ServiceProvider, resolver: AsyncLazyResolveFn<T>) -> Self {
+        Self {
+            provider: services.downgrade(),
+            resolver,
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Resolve the service if it has not been resolved yet.
+    pub async fn resolve(&self) -> Result<Arc<T>, Report<ResolveError>> {
+        if let Some(value) = self.value.get() {
+            return Ok(value.clone());
+        }
+        let services = ServiceProvider::upgrade(&self.provider, type_name::<T>())?;
+        let value = (self.resolver)(services).await?;
+        Ok(self.value.get_or_init(|| value).clone())
+    }
+
+    /// Check if the service has been resolved.
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.value.get().is_some()
+    }
+}
+
+impl<T: ?Sized> Clone for AsyncLazy<T> {
+    /// Clone the handle, including the resolved service if there is one.
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            resolver: self.resolver,
+            value: self.value.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resolves_on_first_use() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_async::<AsyncCountedService>()
+            .build();
+        let counter = services.expect::<ConstructionCounter>();
+
+        // Act
+        let lazy = services
+            .get_lazy_async::<AsyncCountedService>()
+            .expect("should create lazy");
+        let before = counter.get();
+        let _first = lazy.resolve().await.expect("should resolve");
+        let _second = lazy.resolve().await.expect("should resolve");
+
+        // Assert
+        assert_eq!(before, 0);
+        assert_eq!(counter.get(), 1);
+        assert!(lazy.is_resolved());
+    }
+
+    #[tokio::test]
+    async fn singleton_is_shared() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type_async::<AsyncDatabase>()
+            .build();
+        let lazy = services
+            .get_lazy_async::<AsyncDatabase>()
+            .expect("should create lazy");
+
+        // Act
+        let db = lazy.resolve().await.expect("should resolve");
+
+        // Assert
+        let expected = services.expect_async::<AsyncDatabase>().await;
+        assert!(Arc::ptr_eq(&db, &expected));
+    }
+
+    #[tokio::test]
+    async fn dropped_provider_returns_dropped() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type_async::<AsyncDatabase>()
+            .build();
+        let lazy = services
+            .get_lazy_async::<AsyncDatabase>()
+            .expect("should create lazy");
+
+        // Act
+        drop(services);
+        let result = lazy.resolve().await;
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::Dropped);
+    }
+}
@@ -0,0 +1,321 @@
+//! Service resolved on first use.
+use crate::prelude::*;
+
+/// Function that resolves the service of a [`Lazy`].
+type LazyResolveFn<T> = fn(&ServiceProvider) -> Result<Arc<T>, Report<ResolveError>>;
+
+/// Service resolved on first use.
+///
+/// Resolve with [`ServiceProvider::get_lazy`], or `get_lazy_trait` for a trait
+/// object, or declare a `Lazy<T>` field on a struct deriving [`FromServices`].
+///
+/// - The service is resolved by the first [`resolve`](Lazy::resolve) or dereference
+///   and the result is kept by this handle
+/// - A singleton is shared with every other resolution, while a transient is
+///   constructed once per handle
+/// - The handle holds a weak reference to the provider so it can be stored by a
+///   singleton without keeping the provider alive. Resolving after the provider
+///   is dropped returns [`ResolveError::Dropped`]
+///
+/// # Panics
+///
+/// Dereferencing panics if the service cannot be resolved, for example because a
+/// dependency is not registered or the provider has been dropped. Use
+/// [`resolve`](Lazy::resolve) where resolution may fail.
+pub struct Lazy<T: ?Sized> {
+    provider: Weak<ServiceRegistry>,
+    resolver: LazyResolveFn<T>,
+    value: OnceLock<Arc<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Lazy<T> {
+    /// Create a handle that resolves with `resolver` on first use.
+    pub(crate) fn new(services: &ServiceProvider, resolver: LazyResolveFn<T>) -> Self {
+        Self {
+            provider: services.downgrade(),
+            resolver,
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Resolve the service if it has not been resolved yet.
+    pub fn resolve(&self) -> Result<Arc<T>, Report<ResolveError>> {
+        self.resolve_once().map(Arc::clone)
+    }
+
+    /// Check if the service has been resolved.
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Resolve the service once and keep the result.
+    ///
+    /// If two threads race to resolve a transient only the first result is kept.
+    fn resolve_once(&self) -> Result<&Arc<T>, Report<ResolveError>> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        let services = ServiceProvider::upgrade(&self.provider, type_name::<T>())?;
+        let value = (self.resolver)(&services)?;
+        Ok(self.value.get_or_init(|| value))
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Deref for Lazy<T> {
+    type Target = T;
+
+    /// Resolve the service if it has not been resolved yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the service cannot be resolved. Use [`resolve`](Lazy::resolve) to
+    /// handle the error instead.
+    #[track_caller]
+    fn deref(&self) -> &T {
+        self.resolve_once().expect("lazy service should resolve")
+    }
+}
+
+impl<T: ?Sized> Clone for Lazy<T> {
+    /// Clone the handle, including the resolved service if there is one.
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            resolver: self.resolver,
+            value: self.value.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Service that depends on [`CycleB`] through a [`Lazy`] handle.
+    struct CycleA {
+        b: Lazy<CycleB>,
+    }
+
+    impl FromServices for CycleA {
+        type Error = ResolveError;
+
+        fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            let b = services.get_lazy::<CycleB>()?;
+            Ok(Self { b })
+        }
+    }
+
+    /// Service that depends on [`CycleA`].
+    struct CycleB {
+        a: Arc<CycleA>,
+    }
+
+    impl FromServices for CycleB {
+        type Error = ResolveError;
+
+        fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            let a = services.get::<CycleA>()?;
+            Ok(Self { a })
+        }
+    }
+
+    #[test]
+    fn resolves_on_first_use() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type::<CountedService>()
+            .build();
+        let counter = services.expect::<ConstructionCounter>();
+
+        // Act
+        let lazy = services
+            .get_lazy::<CountedService>()
+            .expect("should create lazy");
+        let before = counter.get();
+        let _first = lazy.resolve().expect("should resolve");
+        let _second = lazy.resolve().expect("should resolve");
+
+        // Assert
+        assert_eq!(before, 0);
+        assert_eq!(counter.get(), 1);
+        assert!(lazy.is_resolved());
+    }
+
+    #[test]
+    fn singleton_is_shared() {
+        // Arrange
+        let services = ServiceBuilder::new().with_type::<MemoryCache>().build();
+        let lazy = services
+            .get_lazy::<MemoryCache>()
+            .expect("should create lazy");
+
+        // Act
+        lazy.set("key", "hello");
+
+        // Assert
+        let cache = services.expect::<MemoryCache>();
+        assert_eq!(cache.get("key"), Some(String::from("hello")));
+    }
+
+    #[test]
+    fn transient_is_kept_per_handle() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_transient::<CountedService>()
+            .build();
+        let first = services
+            .get_lazy::<CountedService>()
+            .expect("should create lazy");
+        let second = services
+            .get_lazy::<CountedService>()
+            .expect("should create lazy");
+
+        // Act
+        let _a = first.resolve().expect("should resolve");
+        let _b = first.resolve().expect("should resolve");
+        let _c = second.resolve().expect("should resolve");
+
+        // Assert
+        assert_eq!(services.expect::<ConstructionCounter>().get(), 2);
+    }
+
+    #[test]
+    fn breaks_construction_cycle() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<CycleA>()
+            .with_type::<CycleB>()
+            .build();
+        let a = services.expect::<CycleA>();
+
+        // Act
+        let b = a.b.resolve().expect("should resolve");
+
+        // Assert
+        assert!(Arc::ptr_eq(&a, &b.a));
+    }
+
+    #[test]
+    fn unregistered_type_returns_not_found() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+
+        // Act
+        let result = services.get_lazy::<Config>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn dropped_provider_returns_dropped() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+        let lazy = services.get_lazy::<Config>().expect("should create lazy");
+
+        // Act
+        drop(services);
+        let result = lazy.resolve();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::Dropped);
+    }
+
+    #[test]
+    #[should_panic(expected = "lazy service should resolve")]
+    fn deref_panics_if_provider_dropped() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+        let lazy = services.get_lazy::<Config>().expect("should create lazy");
+        drop(services);
+
+        // Act
+        let _port = lazy.port;
+    }
+
+    #[test]
+    #[should_panic(expected = "lazy service should resolve")]
+    fn deref_panics_if_resolution_fails() {
+        // Arrange
+        let services = ServiceBuilder::new().with_type::<Database>().build();
+        let lazy = services.get_lazy::<Database>().expect("should create lazy");
+
+        // Act
+        let _config = &lazy.config;
+    }
+
+    #[test]
+    fn resolved_value_outlives_provider() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+        let lazy = services.get_lazy::<Config>().expect("should create lazy");
+        let _config = lazy.resolve().expect("should resolve");
+
+        // Act
+        drop(services);
+
+        // Assert
+        assert_eq!(lazy.port, 8080);
+    }
+
+    #[test]
+    fn resolves_from_parent() {
+        // Arrange
+        let parent = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+        let child = parent.child(|builder| builder);
+
+        // Act
+        let lazy = child.get_lazy::<Config>().expect("should create lazy");
+
+        // Assert
+        assert_eq!(lazy.port, 8080);
+    }
+
+    #[test]
+    fn derived_field() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type::<DerivedLazyService>()
+            .build();
+
+        // Act
+        let service = services.expect::<DerivedLazyService>();
+
+        // Assert
+        assert!(!service.config.is_resolved());
+        assert_eq!(service.config.port, 8080);
+    }
+
+    #[cfg(feature = "traits")]
+    #[test]
+    fn trait_object() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_trait::<dyn Get, MemoryCache>()
+            .with_trait::<dyn Set, MemoryCache>()
+            .build();
+        services.expect_trait::<dyn Set>().set("key", "hello");
+
+        // Act
+        let lazy = services
+            .get_lazy_trait::<dyn Get>()
+            .expect("should create lazy");
+
+        // Assert
+        assert_eq!(lazy.get("key"), Some(String::from("hello")));
+    }
+}
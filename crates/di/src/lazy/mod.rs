@@ -0,0 +1,9 @@
+//! Deferred service resolution.
+#[cfg(feature = "async")]
+mod async_lazy;
+mod lazy;
+mod service_provider_lazy;
+
+#[cfg(feature = "async")]
+pub use async_lazy::*;
+pub use lazy::*;
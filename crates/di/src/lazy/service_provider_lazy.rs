@@ -0,0 +1,83 @@
+//! Deferred service resolution.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Get a handle that resolves a concrete type on first use.
+    ///
+    /// Returns [`ResolveError::NotFound`] if the type is not registered.
+    pub fn get_lazy<T: Send + Sync + 'static>(&self) -> Result<Lazy<T>, Report<ResolveError>> {
+        self.check_registered(TypeId::of::<T>(), type_name::<T>())?;
+        Ok(Lazy::new(self, ServiceProvider::get::<T>))
+    }
+
+    /// Get a handle that resolves a trait object on first use.
+    ///
+    /// Returns [`ResolveError::NotFound`] if the trait is not registered.
+    #[cfg(feature = "traits")]
+    pub fn get_lazy_trait<Trait: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<Lazy<Trait>, Report<ResolveError>> {
+        self.check_registered(TypeId::of::<Arc<Trait>>(), type_name::<Arc<Trait>>())?;
+        Ok(Lazy::new(self, ServiceProvider::get_trait::<Trait>))
+    }
+
+    /// Get a handle that resolves a concrete type asynchronously on first use.
+    ///
+    /// Returns [`ResolveError::NotFound`] if the type is not registered.
+    #[cfg(feature = "async")]
+    pub fn get_lazy_async<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<AsyncLazy<T>, Report<ResolveError>> {
+        self.check_registered(TypeId::of::<T>(), type_name::<T>())?;
+        Ok(AsyncLazy::new(self, |services| {
+            Box::pin(async move { services.get_async::<T>().await })
+        }))
+    }
+
+    /// Get a handle that resolves a trait object asynchronously on first use.
+    ///
+    /// Returns [`ResolveError::NotFound`] if the trait is not registered.
+    #[cfg(all(feature = "traits", feature = "async"))]
+    pub fn get_lazy_trait_async<Trait: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<AsyncLazy<Trait>, Report<ResolveError>> {
+        self.check_registered(TypeId::of::<Arc<Trait>>(), type_name::<Arc<Trait>>())?;
+        Ok(AsyncLazy::new(self, |services| {
+            Box::pin(async move { services.get_trait_async::<Trait>().await })
+        }))
+    }
+
+    /// Check the type is registered by this provider or one of its parents.
+    fn check_registered(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<(), Report<ResolveError>> {
+        let registry = &self.registry;
+        if registry.factories.contains_key(&type_id)
+            || registry.instance_registrations.contains_key(&type_id)
+        {
+            return Ok(());
+        }
+        self.parent(type_name)?.check_registered(type_id, type_name)
+    }
+
+    /// Get a weak handle to the provider that does not keep its registry alive.
+    pub(crate) fn downgrade(&self) -> Weak<ServiceRegistry> {
+        Arc::downgrade(&self.registry)
+    }
+
+    /// Get the provider from a weak handle.
+    ///
+    /// Returns [`ResolveError::Dropped`] if the provider has been dropped.
+    pub(crate) fn upgrade(
+        registry: &Weak<ServiceRegistry>,
+        type_name: &'static str,
+    ) -> Result<Self, Report<ResolveError>> {
+        let registry = registry
+            .upgrade()
+            .ok_or_else(|| Report::new(ResolveError::Dropped))
+            .attach("type", type_name)?;
+        Ok(Self { registry })
+    }
+}
@@ -6,9 +6,32 @@ extern crate self as studiole_di;
 
 #[cfg(feature = "async")]
 mod r#async;
+#[cfg(feature = "config")]
+mod config;
 mod extensions;
+mod factory;
+#[cfg(feature = "health")]
+mod health;
+#[cfg(feature = "hosting")]
+mod hosting;
+#[cfg(feature = "jobs")]
+mod jobs;
+mod keyed;
+mod lazy;
+mod lifetime;
+#[cfg(feature = "pool")]
+mod pool;
 pub mod prelude;
 mod schema;
+mod scope;
 mod service_builder;
+mod service_builder_profiles;
 mod service_provider;
+mod service_provider_child;
+mod service_provider_introspection;
+mod service_provider_lifecycle;
+mod service_provider_overrides;
+mod service_provider_with;
 mod traits;
+#[cfg(feature = "ttl")]
+mod ttl;
@@ -0,0 +1,59 @@
+//! Lifetime trait.
+use crate::prelude::*;
+
+/// Decide whether and where constructed instances of a service are cached.
+///
+/// Each resolution calls [`get`](Lifetime::get), and if it returns `None` constructs
+/// an instance and passes it to [`store`](Lifetime::store). Eviction is up to the
+/// lifetime: once `get` stops returning an instance the next resolution constructs
+/// a fresh one.
+///
+/// Register a type with a custom lifetime via
+/// [`ServiceBuilder::with_type_lifetime`]. The built-in singleton, transient, weak
+/// and thread-local lifetimes are implemented with this trait.
+///
+/// The lifetime is shared by forks and child providers of the provider it was
+/// registered with. Use [`LifetimeContext::services`] to tell them apart.
+///
+/// ```ignore
+/// impl Lifetime for PerRound {
+///     fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+///         self.rounds.current().get(&context.type_id()).cloned()
+///     }
+///
+///     fn store(
+///         &self,
+///         context: &LifetimeContext<'_>,
+///         instance: Arc<dyn Any + Send + Sync>,
+///     ) -> Arc<dyn Any + Send + Sync> {
+///         self.rounds.current().entry(context.type_id()).or_insert(instance).clone()
+///     }
+/// }
+/// ```
+pub trait Lifetime: Send + Sync + 'static {
+    /// Get the cached instance, or `None` to construct one.
+    fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>>;
+
+    /// Cache a newly constructed instance.
+    ///
+    /// Returns the instance to resolve, which may be an instance cached by a
+    /// concurrent resolution rather than `instance`.
+    fn store(
+        &self,
+        context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync>;
+
+    /// Evict the instances cached for the provider of `context`.
+    ///
+    /// Called for each registration when the provider shuts down. The default does
+    /// nothing.
+    fn shutdown(&self, _context: &LifetimeContext<'_>) {}
+
+    /// Describe the lifetime in a [`ServiceDescriptor`].
+    ///
+    /// The default is [`ServiceLifetime::Custom`] with the type name of the lifetime.
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::Custom(type_name::<Self>())
+    }
+}
@@ -0,0 +1,51 @@
+//! Lifetime context.
+use crate::prelude::*;
+
+/// Registration being resolved, passed to the methods of a [`Lifetime`].
+pub struct LifetimeContext<'a> {
+    services: &'a ServiceProvider,
+    type_id: TypeId,
+    registration: &'a Registration,
+}
+
+impl<'a> LifetimeContext<'a> {
+    /// Create a context for resolving `registration` from `services`.
+    pub(crate) fn new(
+        services: &'a ServiceProvider,
+        type_id: TypeId,
+        registration: &'a Registration,
+    ) -> Self {
+        Self {
+            services,
+            type_id,
+            registration,
+        }
+    }
+
+    /// Get the provider resolving the service.
+    #[must_use]
+    pub fn services(&self) -> &'a ServiceProvider {
+        self.services
+    }
+
+    /// Get the type of the service.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Get the name of the service type.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.registration.type_name
+    }
+
+    /// Get the registration being resolved.
+    #[cfg_attr(
+        not(feature = "ttl"),
+        expect(dead_code, reason = "used when ttl feature is enabled")
+    )]
+    pub(crate) fn registration(&self) -> &'a Registration {
+        self.registration
+    }
+}
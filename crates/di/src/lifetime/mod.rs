@@ -0,0 +1,15 @@
+//! Service lifetimes.
+mod lifetime;
+mod lifetime_context;
+mod service_builder_lifetime;
+mod singleton_lifetime;
+mod thread_local_lifetime;
+mod transient_lifetime;
+mod weak_lifetime;
+
+pub use lifetime::*;
+pub use lifetime_context::*;
+pub(crate) use singleton_lifetime::*;
+pub(crate) use thread_local_lifetime::*;
+pub(crate) use transient_lifetime::*;
+pub(crate) use weak_lifetime::*;
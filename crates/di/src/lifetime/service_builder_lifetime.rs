@@ -0,0 +1,139 @@
+//! Custom lifetime registration.
+use crate::prelude::*;
+
+impl ServiceBuilder {
+    /// Register a type whose instances are cached by a custom [`Lifetime`].
+    ///
+    /// ```ignore
+    /// builder.with_type_lifetime::<Scoreboard>(PerRound::new(rounds.clone()))
+    /// ```
+    #[must_use]
+    pub fn with_type_lifetime<T: FromServices>(self, lifetime: impl Lifetime) -> Self {
+        self.register_type::<T>(lifetime)
+    }
+
+    /// Register a type for async resolution whose instances are cached by a custom
+    /// [`Lifetime`].
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn with_type_async_lifetime<T: FromServicesAsync>(self, lifetime: impl Lifetime) -> Self {
+        self.register_type_async::<T>(lifetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Game rounds whose services are cached until the round ends.
+    #[derive(Default)]
+    struct Rounds {
+        instances: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+        shutdowns: AtomicUsize,
+    }
+
+    impl Rounds {
+        fn end_round(&self) {
+            self.instances.lock().expect("should lock").clear();
+        }
+    }
+
+    /// Lifetime caching one instance per round.
+    struct PerRound(Arc<Rounds>);
+
+    impl Lifetime for PerRound {
+        fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+            let instances = self.0.instances.lock().expect("should lock");
+            instances.get(&context.type_id()).cloned()
+        }
+
+        fn store(
+            &self,
+            context: &LifetimeContext<'_>,
+            instance: Arc<dyn Any + Send + Sync>,
+        ) -> Arc<dyn Any + Send + Sync> {
+            let mut instances = self.0.instances.lock().expect("should lock");
+            Arc::clone(instances.entry(context.type_id()).or_insert(instance))
+        }
+
+        fn shutdown(&self, _context: &LifetimeContext<'_>) {
+            self.0.shutdowns.fetch_add(1, Ordering::SeqCst);
+            self.0.end_round();
+        }
+    }
+
+    fn build(rounds: &Arc<Rounds>) -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_type_lifetime::<MemoryCache>(PerRound(rounds.clone()))
+            .build()
+    }
+
+    #[test]
+    fn custom_lifetime_caches_until_evicted() {
+        // Arrange
+        let rounds = Arc::new(Rounds::default());
+        let services = build(&rounds);
+        let first = services.expect::<MemoryCache>();
+
+        // Act
+        let same_round = services.expect::<MemoryCache>();
+        rounds.end_round();
+        let next_round = services.expect::<MemoryCache>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &same_round));
+        assert!(!Arc::ptr_eq(&first, &next_round));
+    }
+
+    #[test]
+    fn shutdown_calls_custom_lifetime() {
+        // Arrange
+        let rounds = Arc::new(Rounds::default());
+        let services = build(&rounds);
+        let _cache = services.expect::<MemoryCache>();
+
+        // Act
+        services.shutdown().expect("should shut down");
+
+        // Assert
+        assert_eq!(rounds.shutdowns.load(Ordering::SeqCst), 1);
+        assert!(rounds.instances.lock().expect("should lock").is_empty());
+    }
+
+    #[test]
+    fn descriptors_report_custom_lifetime() {
+        // Arrange
+        let services = build(&Arc::new(Rounds::default()));
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let descriptor = descriptors.first().expect("should describe service");
+        assert!(matches!(
+            descriptor.lifetime,
+            ServiceLifetime::Custom(name) if name.ends_with("::PerRound")
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_custom_lifetime_caches_until_evicted() {
+        // Arrange
+        let rounds = Arc::new(Rounds::default());
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type_async_lifetime::<AsyncDatabase>(PerRound(rounds.clone()))
+            .build();
+        let first = services.expect_async::<AsyncDatabase>().await;
+
+        // Act
+        let same_round = services.expect_async::<AsyncDatabase>().await;
+        rounds.end_round();
+        let next_round = services.expect_async::<AsyncDatabase>().await;
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &same_round));
+        assert!(!Arc::ptr_eq(&first, &next_round));
+    }
+}
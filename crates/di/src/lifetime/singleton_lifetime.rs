@@ -0,0 +1,30 @@
+//! Singleton lifetime.
+use crate::prelude::*;
+
+/// Cache after first construction and return the same instance on subsequent calls.
+#[derive(Clone, Copy)]
+pub(crate) struct SingletonLifetime;
+
+impl Lifetime for SingletonLifetime {
+    fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+        context.services().get_cached(context.type_id())
+    }
+
+    fn store(
+        &self,
+        context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync> {
+        let mut instances = context
+            .services()
+            .registry
+            .instances
+            .lock()
+            .expect("should be able to lock instances");
+        Arc::clone(instances.entry(context.type_id()).or_insert(instance))
+    }
+
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::Singleton
+    }
+}
@@ -0,0 +1,242 @@
+//! Thread-local lifetime.
+use crate::prelude::*;
+
+thread_local! {
+    /// Instances of thread-local registrations cached by this thread, keyed by the
+    /// address of the registry they belong to.
+    static THREAD_INSTANCES: RefCell<HashMap<usize, ThreadInstances>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Instances cached by one thread for one provider.
+struct ThreadInstances {
+    /// Registry the instances belong to.
+    ///
+    /// Holding a weak reference keeps the address from being reused by another
+    /// registry while the instances are cached.
+    registry: Weak<ServiceRegistry>,
+    /// Shutdown generation of the registry when the instances were cached.
+    generation: usize,
+    /// Cached instances keyed by type.
+    instances: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+/// Cache one instance per thread, dropped when the thread exits.
+#[derive(Clone, Copy)]
+pub(crate) struct ThreadLocalLifetime;
+
+impl Lifetime for ThreadLocalLifetime {
+    fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+        let services = context.services();
+        let key = services.thread_local_key();
+        let generation = services.thread_local_generation();
+        THREAD_INSTANCES.with_borrow(|threads| {
+            let entry = threads.get(&key)?;
+            if entry.generation != generation {
+                return None;
+            }
+            entry.instances.get(&context.type_id()).cloned()
+        })
+    }
+
+    fn store(
+        &self,
+        context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync> {
+        let services = context.services();
+        let key = services.thread_local_key();
+        let generation = services.thread_local_generation();
+        // Discarded instances are dropped after the borrow ends, in case their drop
+        // resolves another thread-local service.
+        let (value, _discarded) = THREAD_INSTANCES.with_borrow_mut(|threads| {
+            let mut discarded: Vec<ThreadInstances> = threads
+                .extract_if(|_, entry| entry.registry.strong_count() == 0)
+                .map(|(_, entry)| entry)
+                .collect();
+            let entry = threads.entry(key).or_insert_with(|| ThreadInstances {
+                registry: Arc::downgrade(&services.registry),
+                generation,
+                instances: HashMap::new(),
+            });
+            if entry.generation != generation {
+                discarded.push(ThreadInstances {
+                    registry: entry.registry.clone(),
+                    generation: entry.generation,
+                    instances: take(&mut entry.instances),
+                });
+                entry.generation = generation;
+            }
+            let value = Arc::clone(entry.instances.entry(context.type_id()).or_insert(instance));
+            (value, discarded)
+        });
+        value
+    }
+
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::ThreadLocal
+    }
+}
+
+impl ServiceProvider {
+    /// Discard the instances of thread-local registrations.
+    ///
+    /// Instances cached by this thread are dropped now, and those cached by other
+    /// threads are dropped when the thread next resolves them or exits.
+    pub(crate) fn clear_thread_local(&self) {
+        self.registry
+            .thread_local_generation
+            .fetch_add(1, Ordering::SeqCst);
+        let key = self.thread_local_key();
+        let _discarded = THREAD_INSTANCES.with_borrow_mut(|threads| threads.remove(&key));
+    }
+
+    /// Get the key of this provider in the thread-local cache.
+    fn thread_local_key(&self) -> usize {
+        Arc::as_ptr(&self.registry).addr()
+    }
+
+    /// Get the current shutdown generation.
+    fn thread_local_generation(&self) -> usize {
+        self.registry.thread_local_generation.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::{scope, spawn};
+    #[cfg(feature = "async")]
+    use tokio::task::{spawn as spawn_task, spawn_blocking};
+
+    fn build() -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_type_thread_local::<MemoryCache>()
+            .build()
+    }
+
+    #[test]
+    fn same_thread_shares_instance() {
+        // Arrange
+        let services = build();
+
+        // Act
+        let first = services.expect::<MemoryCache>();
+        let second = services.expect::<MemoryCache>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn other_thread_gets_own_instance() {
+        // Arrange
+        let services = build();
+        let local = services.expect::<MemoryCache>();
+
+        // Act
+        let (first, second) = scope(|scope| {
+            scope
+                .spawn(|| {
+                    (
+                        services.expect::<MemoryCache>(),
+                        services.expect::<MemoryCache>(),
+                    )
+                })
+                .join()
+                .expect("should join")
+        });
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &local));
+    }
+
+    #[test]
+    fn instance_dropped_when_thread_exits() {
+        // Arrange
+        let services = build();
+        let worker = services.clone();
+
+        // Act
+        let weak = spawn(move || Arc::downgrade(&worker.expect::<MemoryCache>()))
+            .join()
+            .expect("should join");
+
+        // Assert
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn fork_has_own_instance() {
+        // Arrange
+        let services = build();
+        let fork = services.fork();
+
+        // Act
+        let from_fork = fork.expect::<MemoryCache>();
+
+        // Assert
+        assert!(!Arc::ptr_eq(&from_fork, &services.expect::<MemoryCache>()));
+    }
+
+    #[test]
+    fn shutdown_drops_instance() {
+        // Arrange
+        let services = build();
+        let weak = Arc::downgrade(&services.expect::<MemoryCache>());
+
+        // Act
+        services.shutdown().expect("should shut down");
+
+        // Assert
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn descriptors_report_thread_local() {
+        // Arrange
+        let services = build();
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let descriptor = descriptors.first().expect("should describe service");
+        assert_eq!(descriptor.lifetime, ServiceLifetime::ThreadLocal);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_tasks_on_one_thread_share_instance() {
+        // Arrange
+        let services = build();
+        let first = services.expect_async::<MemoryCache>().await;
+
+        // Act
+        let worker = services.clone();
+        let second = spawn_task(async move { worker.expect_async::<MemoryCache>().await })
+            .await
+            .expect("should join");
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_blocking_thread_gets_own_instance() {
+        // Arrange
+        let services = build();
+        let first = services.expect_async::<MemoryCache>().await;
+
+        // Act
+        let worker = services.clone();
+        let blocking = spawn_blocking(move || worker.expect::<MemoryCache>())
+            .await
+            .expect("should join");
+
+        // Assert
+        assert!(!Arc::ptr_eq(&first, &blocking));
+    }
+}
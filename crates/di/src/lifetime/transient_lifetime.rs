@@ -0,0 +1,24 @@
+//! Transient lifetime.
+use crate::prelude::*;
+
+/// Construct a fresh instance on every resolution.
+#[derive(Clone, Copy)]
+pub(crate) struct TransientLifetime;
+
+impl Lifetime for TransientLifetime {
+    fn get(&self, _context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+        None
+    }
+
+    fn store(
+        &self,
+        _context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync> {
+        instance
+    }
+
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::Transient
+    }
+}
@@ -0,0 +1,45 @@
+//! Weak lifetime.
+use crate::prelude::*;
+
+/// Share the instance while any consumer holds it, then construct afresh.
+#[derive(Clone, Copy)]
+pub(crate) struct WeakLifetime;
+
+impl WeakLifetime {
+    /// Lock the weak references to instances of weak registrations.
+    fn lock_instances<'a>(
+        context: &LifetimeContext<'a>,
+    ) -> MutexGuard<'a, HashMap<TypeId, Weak<dyn Any + Send + Sync>>> {
+        context
+            .services()
+            .registry
+            .weak_instances
+            .lock()
+            .expect("should be able to lock weak instances")
+    }
+}
+
+impl Lifetime for WeakLifetime {
+    fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+        Self::lock_instances(context)
+            .get(&context.type_id())
+            .and_then(Weak::upgrade)
+    }
+
+    fn store(
+        &self,
+        context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync> {
+        let mut instances = Self::lock_instances(context);
+        if let Some(existing) = instances.get(&context.type_id()).and_then(Weak::upgrade) {
+            return existing;
+        }
+        instances.insert(context.type_id(), Arc::downgrade(&instance));
+        instance
+    }
+
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::Weak
+    }
+}
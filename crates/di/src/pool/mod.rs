@@ -0,0 +1,12 @@
+//! Pooled services.
+mod pool;
+mod pool_config;
+mod pooled;
+mod reset;
+mod service_builder_pool;
+mod service_provider_pool;
+
+pub(crate) use pool::*;
+pub use pool_config::*;
+pub use pooled::*;
+pub use reset::*;
@@ -0,0 +1,220 @@
+//! Pool storage.
+use crate::prelude::*;
+
+/// Closure that constructs an instance for a pool.
+pub(crate) type PoolCreateFn = Arc<
+    dyn Fn(&ServiceProvider) -> Result<Box<dyn Any + Send + Sync>, Report<ResolveError>>
+        + Send
+        + Sync,
+>;
+
+/// Closure that resets a type-erased pooled instance.
+pub(crate) type ResetFn = Arc<dyn Fn(&mut (dyn Any + Send + Sync)) + Send + Sync>;
+
+/// Internal registration entry for a pooled service.
+#[derive(Clone)]
+pub(crate) struct PoolRegistration {
+    /// Name of the registered type, used for diagnostics.
+    pub type_name: &'static str,
+    /// Size limits of the pool.
+    pub config: PoolConfig,
+    /// Closure that constructs an instance.
+    pub create: PoolCreateFn,
+    /// Profile that contributed the registration, or `None` if unconditional.
+    pub profile: Option<String>,
+}
+
+/// Instances of a pooled registration.
+///
+/// Every instance that is not idle is held by a [`Pooled`] guard, or is being
+/// constructed for one, and each guard holds a permit. The number of instances
+/// therefore never exceeds the maximum.
+pub(crate) struct Pool {
+    config: PoolConfig,
+    create: PoolCreateFn,
+    reset: Option<ResetFn>,
+    dispose: Option<DisposeFn>,
+    permits: Arc<Semaphore>,
+    state: Mutex<PoolState>,
+    waiting: AtomicUsize,
+}
+
+/// Mutable state of a [`Pool`].
+struct PoolState {
+    /// Instances waiting to be borrowed.
+    idle: Vec<Box<dyn Any + Send + Sync>>,
+    /// Number of instances that exist, whether idle or in use.
+    size: usize,
+    /// Whether the provider has shut down, so returned instances are discarded.
+    closed: bool,
+}
+
+impl Pool {
+    /// Create a pool holding the minimum number of instances.
+    pub(crate) fn new(
+        services: &ServiceProvider,
+        registration: &PoolRegistration,
+        reset: Option<ResetFn>,
+        dispose: Option<DisposeFn>,
+    ) -> Result<Self, Report<ResolveError>> {
+        let config = registration.config;
+        let idle = (0..config.min())
+            .map(|_| (registration.create)(services))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            config,
+            create: registration.create.clone(),
+            reset,
+            dispose,
+            permits: Arc::new(Semaphore::new(config.max())),
+            state: Mutex::new(PoolState {
+                size: idle.len(),
+                idle,
+                closed: false,
+            }),
+            waiting: AtomicUsize::new(0),
+        })
+    }
+
+    /// Wait for a permit, then borrow an instance.
+    ///
+    /// Returns [`ResolveError::PoolExhausted`] if the configured timeout elapses.
+    pub(crate) async fn acquire<T: Send + Sync + 'static>(
+        self: &Arc<Self>,
+        services: &ServiceProvider,
+    ) -> Result<Pooled<T>, Report<ResolveError>> {
+        let permit = {
+            let _waiting = WaitingGuard::new(&self.waiting);
+            let acquire = self.permits.clone().acquire_owned();
+            match self.config.timeout() {
+                Some(duration) => timeout(duration, acquire)
+                    .await
+                    .map_err(|_| Report::new(ResolveError::PoolExhausted))?,
+                None => acquire.await,
+            }
+        };
+        let permit =
+            permit.map_err(|_| Report::new(ResolveError::ShutDown(services.lifecycle())))?;
+        self.checkout(services, permit)
+    }
+
+    /// Borrow an instance if a permit is available without waiting.
+    ///
+    /// Returns [`ResolveError::PoolExhausted`] if every permit is held.
+    pub(crate) fn try_acquire<T: Send + Sync + 'static>(
+        self: &Arc<Self>,
+        services: &ServiceProvider,
+    ) -> Result<Pooled<T>, Report<ResolveError>> {
+        let permit = self
+            .permits
+            .clone()
+            .try_acquire_owned()
+            .map_err(|error| match error {
+                TryAcquireError::NoPermits => Report::new(ResolveError::PoolExhausted),
+                TryAcquireError::Closed => {
+                    Report::new(ResolveError::ShutDown(services.lifecycle()))
+                }
+            })?;
+        self.checkout(services, permit)
+    }
+
+    /// Take an idle instance, or construct one if none is idle.
+    fn checkout<T: Send + Sync + 'static>(
+        self: &Arc<Self>,
+        services: &ServiceProvider,
+        permit: OwnedSemaphorePermit,
+    ) -> Result<Pooled<T>, Report<ResolveError>> {
+        let idle = {
+            let mut state = self.lock_state();
+            let idle = state.idle.pop();
+            if idle.is_none() {
+                state.size += 1;
+            }
+            idle
+        };
+        let instance = match idle {
+            Some(instance) => instance,
+            None => (self.create)(services).inspect_err(|_| self.lock_state().size -= 1)?,
+        };
+        let value = instance
+            .downcast::<T>()
+            .expect("pooled instance should match its registration");
+        Ok(Pooled::new(value, self.clone(), permit))
+    }
+
+    /// Reset an instance and return it to the pool.
+    ///
+    /// Once the pool is closed the instance is disposed instead.
+    pub(crate) fn release(&self, mut instance: Box<dyn Any + Send + Sync>) {
+        if let Some(reset) = &self.reset {
+            reset(instance.as_mut());
+        }
+        let mut state = self.lock_state();
+        if !state.closed {
+            state.idle.push(instance);
+            return;
+        }
+        state.size -= 1;
+        drop(state);
+        self.dispose(instance);
+    }
+
+    /// Dispose every idle instance and reject further acquisitions.
+    ///
+    /// Instances still in use are disposed when their guard is dropped.
+    pub(crate) fn close(&self) {
+        self.permits.close();
+        let idle = {
+            let mut state = self.lock_state();
+            state.closed = true;
+            state.size -= state.idle.len();
+            take(&mut state.idle)
+        };
+        for instance in idle {
+            self.dispose(instance);
+        }
+    }
+
+    /// Get a snapshot of the pool.
+    pub(crate) fn stats(&self) -> PoolStats {
+        let state = self.lock_state();
+        PoolStats {
+            size: state.size,
+            idle: state.idle.len(),
+            in_use: self
+                .config
+                .max()
+                .saturating_sub(self.permits.available_permits()),
+            waiting: self.waiting.load(Ordering::SeqCst),
+            ..PoolStats::empty(self.config)
+        }
+    }
+
+    /// Dispose an instance if its type has a disposal hook.
+    fn dispose(&self, instance: Box<dyn Any + Send + Sync>) {
+        if let Some(dispose) = &self.dispose {
+            dispose(Arc::from(instance));
+        }
+    }
+
+    /// Lock the mutable state.
+    fn lock_state(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().expect("should be able to lock pool")
+    }
+}
+
+/// Count a caller as waiting until dropped, including when the wait is cancelled.
+struct WaitingGuard<'a>(&'a AtomicUsize);
+
+impl<'a> WaitingGuard<'a> {
+    fn new(waiting: &'a AtomicUsize) -> Self {
+        waiting.fetch_add(1, Ordering::SeqCst);
+        Self(waiting)
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,119 @@
+//! Pool configuration and statistics.
+use crate::prelude::*;
+
+/// Size limits of a pooled service.
+///
+/// Pass to [`ServiceBuilder::with_type_pooled`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PoolConfig {
+    min: usize,
+    max: usize,
+    timeout: Option<Duration>,
+}
+
+impl PoolConfig {
+    /// Allow at most `max` instances to exist at once.
+    ///
+    /// A `max` of zero is treated as one.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self {
+            min: 0,
+            max: max.max(1),
+            timeout: None,
+        }
+    }
+
+    /// Construct `min` instances up front and keep them in the pool.
+    ///
+    /// The pool is filled by [`ServiceProvider::init`] and `ServiceProvider::warm_up`,
+    /// or by the first acquisition. `min` is capped at the maximum.
+    #[must_use]
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = min.min(self.max);
+        self
+    }
+
+    /// Stop waiting for an instance after `timeout`.
+    ///
+    /// Without a timeout [`get_pooled`](ServiceProvider::get_pooled) waits until an
+    /// instance is handed back.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the number of instances constructed up front.
+    pub(crate) fn min(&self) -> usize {
+        self.min
+    }
+
+    /// Get the maximum number of instances.
+    pub(crate) fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Get how long to wait for an instance.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+/// Snapshot of a pool, returned by [`ServiceProvider::pool_stats`] and reported by
+/// [`ServiceProvider::descriptors`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PoolStats {
+    /// Number of instances that exist, whether idle or in use.
+    pub size: usize,
+    /// Number of instances waiting in the pool.
+    pub idle: usize,
+    /// Number of instances held by [`Pooled`] guards.
+    pub in_use: usize,
+    /// Number of callers waiting for an instance.
+    pub waiting: usize,
+    /// Number of instances constructed up front.
+    pub min: usize,
+    /// Maximum number of instances.
+    pub max: usize,
+}
+
+impl PoolStats {
+    /// Statistics of a pool that has not been created yet.
+    pub(crate) fn empty(config: PoolConfig) -> Self {
+        Self {
+            size: 0,
+            idle: 0,
+            in_use: 0,
+            waiting: 0,
+            min: config.min,
+            max: config.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_is_capped_at_max() {
+        // Arrange
+        let config = PoolConfig::new(2);
+
+        // Act
+        let config = config.with_min(5);
+
+        // Assert
+        assert_eq!(config.min(), 2);
+    }
+
+    #[test]
+    fn zero_max_is_one() {
+        // Act
+        let config = PoolConfig::new(0);
+
+        // Assert
+        assert_eq!(config.max(), 1);
+    }
+}
@@ -0,0 +1,50 @@
+//! Guard over a pooled instance.
+use crate::prelude::*;
+
+/// Instance borrowed from a pool, handed back when dropped.
+///
+/// Returned by [`ServiceProvider::get_pooled`] and [`ServiceProvider::try_get_pooled`].
+/// The instance is not shared while borrowed, so it can be mutated through the guard.
+pub struct Pooled<T: Send + Sync + 'static> {
+    value: Option<Box<T>>,
+    pool: Arc<Pool>,
+    /// Released after the instance is back in the pool.
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<T: Send + Sync + 'static> Pooled<T> {
+    /// Create a guard over an instance borrowed from `pool`.
+    pub(crate) fn new(value: Box<T>, pool: Arc<Pool>, permit: OwnedSemaphorePermit) -> Self {
+        Self {
+            value: Some(value),
+            pool,
+            _permit: permit,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Deref for Pooled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+            .as_deref()
+            .expect("pooled instance should be held until dropped")
+    }
+}
+
+impl<T: Send + Sync + 'static> DerefMut for Pooled<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+            .as_deref_mut()
+            .expect("pooled instance should be held until dropped")
+    }
+}
+
+impl<T: Send + Sync + 'static> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.pool.release(value);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Pooled service reset trait.
+
+/// Restore a pooled service to a clean state before it is reused.
+///
+/// Registered via [`ServiceBuilder::with_reset`](crate::prelude::ServiceBuilder::with_reset).
+/// Called each time a [`Pooled`](crate::prelude::Pooled) guard hands the instance back
+/// to its pool.
+pub trait Reset: Send + Sync + 'static {
+    /// Clear any state left by the previous user.
+    fn reset(&mut self);
+}
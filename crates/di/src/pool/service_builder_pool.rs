@@ -0,0 +1,47 @@
+//! Pooled service registration.
+use crate::prelude::*;
+
+impl ServiceBuilder {
+    /// Register a type whose instances are borrowed from a pool and reused.
+    ///
+    /// Resolve with [`get_pooled`](ServiceProvider::get_pooled) or
+    /// [`try_get_pooled`](ServiceProvider::try_get_pooled).
+    ///
+    /// ```ignore
+    /// builder.with_type_pooled::<Connection>(PoolConfig::new(10).with_min(2))
+    /// ```
+    #[must_use]
+    pub fn with_type_pooled<T: FromServices>(mut self, config: PoolConfig) -> Self {
+        let create: PoolCreateFn = Arc::new(|services: &ServiceProvider| {
+            let instance = T::from_services(services)
+                .change_context(ResolveError::Factory)
+                .attach("type", type_name::<T>())?;
+            let instance: Box<dyn Any + Send + Sync> = Box::new(instance);
+            Ok(instance)
+        });
+        self.pooled.insert(
+            TypeId::of::<T>(),
+            PoolRegistration {
+                type_name: type_name::<T>(),
+                config,
+                create,
+                profile: None,
+            },
+        );
+        self
+    }
+
+    /// Register a hook that resets instances of `T` when they are handed back to
+    /// their pool.
+    #[must_use]
+    pub fn with_reset<T: Reset>(mut self) -> Self {
+        let reset: ResetFn = Arc::new(|dynamic| {
+            dynamic
+                .downcast_mut::<T>()
+                .expect("pooled instance should match its registration")
+                .reset();
+        });
+        self.resetters.insert(TypeId::of::<T>(), reset);
+        self
+    }
+}
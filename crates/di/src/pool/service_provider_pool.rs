@@ -0,0 +1,352 @@
+//! Pooled service resolution.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Borrow an instance of a pooled type, waiting until one is available.
+    ///
+    /// - Returns an idle instance, or constructs one if fewer than the maximum exist
+    /// - Otherwise waits until a [`Pooled`] guard is dropped
+    ///
+    /// - Returns [`ResolveError::PoolExhausted`] if the timeout set by
+    ///   [`PoolConfig::with_timeout`] elapses first
+    /// - Returns [`ResolveError::NotFound`] if the type is not registered with
+    ///   [`with_type_pooled`](ServiceBuilder::with_type_pooled)
+    pub async fn get_pooled<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<Pooled<T>, Report<ResolveError>> {
+        let type_name = type_name::<T>();
+        trace!(type_name, "Acquiring pooled service");
+        let pool = self.pool(TypeId::of::<T>(), type_name)?;
+        pool.acquire(self).await.attach("type", type_name)
+    }
+
+    /// Borrow an instance of a pooled type without waiting.
+    ///
+    /// - Returns [`ResolveError::PoolExhausted`] if the maximum number of instances
+    ///   are in use
+    /// - Returns [`ResolveError::NotFound`] if the type is not registered with
+    ///   [`with_type_pooled`](ServiceBuilder::with_type_pooled)
+    pub fn try_get_pooled<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<Pooled<T>, Report<ResolveError>> {
+        let type_name = type_name::<T>();
+        trace!(type_name, "Acquiring pooled service");
+        let pool = self.pool(TypeId::of::<T>(), type_name)?;
+        pool.try_acquire(self).attach("type", type_name)
+    }
+
+    /// Get a snapshot of the pool of a pooled type.
+    ///
+    /// Returns `None` if the type is not registered with
+    /// [`with_type_pooled`](ServiceBuilder::with_type_pooled).
+    #[must_use]
+    pub fn pool_stats<T: 'static>(&self) -> Option<PoolStats> {
+        self.pool_stats_of(TypeId::of::<T>())
+    }
+
+    /// Get a snapshot of the pool of a type, walking parent providers.
+    pub(crate) fn pool_stats_of(&self, type_id: TypeId) -> Option<PoolStats> {
+        let Some(registration) = self.registry.pooled.get(&type_id) else {
+            return self.registry.parent.as_ref()?.pool_stats_of(type_id);
+        };
+        let pool = self.lock_pools().get(&type_id).cloned();
+        Some(pool.map_or_else(
+            || PoolStats::empty(registration.config),
+            |pool| pool.stats(),
+        ))
+    }
+
+    /// Create every pool registered with this provider, constructing the minimum
+    /// number of instances of each.
+    pub(crate) fn fill_pools(&self) -> Result<(), Report<ResolveError>> {
+        for (type_id, registration) in &self.registry.pooled {
+            self.pool(*type_id, registration.type_name)?;
+        }
+        Ok(())
+    }
+
+    /// Close every pool, disposing idle instances.
+    pub(crate) fn close_pools(&self) {
+        let pools = take(&mut *self.lock_pools());
+        for pool in pools.into_values() {
+            pool.close();
+        }
+    }
+
+    /// Get the pool of a type, creating it if necessary.
+    ///
+    /// A pool created by a concurrent call may win the race, in which case the
+    /// instances of this one are disposed.
+    fn pool(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<Arc<Pool>, Report<ResolveError>> {
+        self.check_lifecycle(type_id, type_name)?;
+        let Some(registration) = self.registry.pooled.get(&type_id) else {
+            return self.parent(type_name)?.pool(type_id, type_name);
+        };
+        if let Some(pool) = self.lock_pools().get(&type_id) {
+            return Ok(pool.clone());
+        }
+        let reset = self.registry.resetters.get(&type_id).cloned();
+        let dispose = self.registry.disposers.get(&type_id).cloned();
+        let created = Arc::new(Pool::new(self, registration, reset, dispose)?);
+        let pool = self
+            .lock_pools()
+            .entry(type_id)
+            .or_insert_with(|| created.clone())
+            .clone();
+        if !Arc::ptr_eq(&pool, &created) {
+            created.close();
+        }
+        Ok(pool)
+    }
+
+    /// Lock the pools of pooled registrations.
+    fn lock_pools(&self) -> MutexGuard<'_, HashMap<TypeId, Arc<Pool>>> {
+        self.registry
+            .pools
+            .lock()
+            .expect("should be able to lock pools")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::task::{spawn, yield_now};
+
+    /// Record of constructed and disposed buffers.
+    #[derive(Default)]
+    struct BufferLog {
+        created: AtomicUsize,
+        disposed: AtomicUsize,
+    }
+
+    /// Reusable buffer.
+    struct Buffer {
+        log: Arc<BufferLog>,
+        id: usize,
+        data: Vec<u8>,
+    }
+
+    impl FromServices for Buffer {
+        type Error = ResolveError;
+
+        fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            let log = services.get::<BufferLog>()?;
+            let id = log.created.fetch_add(1, Ordering::SeqCst);
+            Ok(Self {
+                log,
+                id,
+                data: Vec::new(),
+            })
+        }
+    }
+
+    impl Reset for Buffer {
+        fn reset(&mut self) {
+            self.data.clear();
+        }
+    }
+
+    impl Dispose for Buffer {
+        fn dispose(&self) {
+            self.log.disposed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn build(config: PoolConfig) -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_instance(BufferLog::default())
+            .with_type_pooled::<Buffer>(config)
+            .with_reset::<Buffer>()
+            .with_dispose::<Buffer>()
+            .build()
+    }
+
+    fn acquire(services: &ServiceProvider) -> Pooled<Buffer> {
+        services.try_get_pooled::<Buffer>().expect("should acquire")
+    }
+
+    #[test]
+    fn returned_instance_is_reset_and_reused() {
+        // Arrange
+        let services = build(PoolConfig::new(2));
+        let mut first = acquire(&services);
+        first.data.push(1);
+        let first_id = first.id;
+        drop(first);
+
+        // Act
+        let second = acquire(&services);
+
+        // Assert
+        assert_eq!(second.id, first_id);
+        assert!(second.data.is_empty());
+        assert_eq!(
+            services
+                .expect::<BufferLog>()
+                .created
+                .load(Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[test]
+    fn try_get_pooled_returns_exhausted_at_max() {
+        // Arrange
+        let services = build(PoolConfig::new(1));
+        let _held = acquire(&services);
+
+        // Act
+        let result = services.try_get_pooled::<Buffer>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::PoolExhausted);
+    }
+
+    #[tokio::test]
+    async fn get_pooled_waits_for_release() {
+        // Arrange
+        let services = build(PoolConfig::new(1));
+        let held = acquire(&services);
+        let held_id = held.id;
+        let waiter = services.clone();
+        let pending = spawn(async move {
+            let buffer = waiter.get_pooled::<Buffer>().await.expect("should acquire");
+            buffer.id
+        });
+        while services.pool_stats::<Buffer>().map(|stats| stats.waiting) != Some(1) {
+            yield_now().await;
+        }
+
+        // Act
+        drop(held);
+        let id = pending.await.expect("should join");
+
+        // Assert
+        assert_eq!(id, held_id);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn get_pooled_times_out() {
+        // Arrange
+        let services = build(PoolConfig::new(1).with_timeout(Duration::from_secs(1)));
+        let _held = acquire(&services);
+
+        // Act
+        let result = services.get_pooled::<Buffer>().await;
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::PoolExhausted);
+        let stats = services.pool_stats::<Buffer>().expect("should have stats");
+        assert_eq!(stats.waiting, 0);
+    }
+
+    #[test]
+    fn init_fills_to_min() {
+        // Arrange
+        let services = build(PoolConfig::new(4).with_min(2));
+
+        // Act
+        let services = services.init().expect("should init");
+
+        // Assert
+        let stats = services.pool_stats::<Buffer>().expect("should have stats");
+        assert_eq!(stats.size, 2);
+        assert_eq!(stats.idle, 2);
+        assert_eq!(stats.in_use, 0);
+    }
+
+    #[test]
+    fn stats_track_usage() {
+        // Arrange
+        let services = build(PoolConfig::new(3));
+        let _first = acquire(&services);
+        let second = acquire(&services);
+
+        // Act
+        drop(second);
+        let stats = services.pool_stats::<Buffer>();
+
+        // Assert
+        let expected = PoolStats {
+            size: 2,
+            idle: 1,
+            in_use: 1,
+            waiting: 0,
+            min: 0,
+            max: 3,
+        };
+        assert_eq!(stats, Some(expected));
+    }
+
+    #[test]
+    fn shutdown_disposes_idle_and_returned_instances() {
+        // Arrange
+        let services = build(PoolConfig::new(2));
+        let log = services.expect::<BufferLog>();
+        let held = acquire(&services);
+        drop(acquire(&services));
+
+        // Act
+        services.shutdown().expect("should shut down");
+        let disposed_at_shutdown = log.disposed.load(Ordering::SeqCst);
+        drop(held);
+
+        // Assert
+        assert_eq!(disposed_at_shutdown, 1);
+        assert_eq!(log.disposed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn child_shares_parent_pool() {
+        // Arrange
+        let parent = build(PoolConfig::new(1));
+        let child = parent.child(|builder| builder);
+        let _held = acquire(&parent);
+
+        // Act
+        let result = child.try_get_pooled::<Buffer>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::PoolExhausted);
+    }
+
+    #[test]
+    fn unregistered_type_returns_not_found() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+
+        // Act
+        let result = services.try_get_pooled::<Buffer>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn descriptors_report_pool_stats() {
+        // Arrange
+        let services = build(PoolConfig::new(2));
+        let _held = acquire(&services);
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let descriptor = descriptors
+            .iter()
+            .find(|descriptor| descriptor.type_name.ends_with("::Buffer"))
+            .expect("should describe pooled service");
+        assert_eq!(descriptor.lifetime, ServiceLifetime::Pooled);
+        let stats = descriptor.pool.expect("should report stats");
+        assert_eq!(stats.in_use, 1);
+    }
+}
@@ -2,23 +2,116 @@
 
 #[cfg(feature = "async")]
 pub use crate::r#async::*;
+#[cfg(feature = "config")]
+pub use crate::config::*;
 pub(crate) use crate::extensions::*;
+pub use crate::factory::*;
+#[cfg(feature = "health")]
+pub use crate::health::*;
+#[cfg(feature = "hosting")]
+pub use crate::hosting::*;
+#[cfg(feature = "jobs")]
+pub use crate::jobs::*;
+pub(crate) use crate::keyed::*;
+pub use crate::lazy::*;
+pub use crate::lifetime::*;
+#[cfg(feature = "pool")]
+pub use crate::pool::*;
 pub use crate::schema::*;
+pub use crate::scope::*;
 pub use crate::service_builder::*;
 pub use crate::service_provider::*;
+#[cfg(feature = "traits")]
+pub use crate::traits::*;
+#[cfg(feature = "ttl")]
+pub use crate::ttl::*;
 pub use studiole_di_macros::FromServices;
 #[cfg(feature = "async")]
 pub use studiole_di_macros::FromServicesAsync;
 
+#[cfg(feature = "jobs")]
+pub(crate) use chrono::{DateTime, TimeDelta, Utc};
+#[cfg(feature = "jobs")]
+pub(crate) use cron::Schedule as CronSchedule;
+#[cfg(feature = "async")]
+pub(crate) use futures_util::future::join_all;
+#[cfg(feature = "async")]
+pub(crate) use futures_util::lock::Mutex as AsyncMutex;
+#[cfg(all(feature = "health", feature = "serde"))]
+pub(crate) use serde::Serialize;
+#[cfg(feature = "config")]
+pub(crate) use serde::de::DeserializeOwned;
+#[cfg(feature = "config")]
+pub(crate) use serde_json::{Map as JsonMap, Value as JsonValue};
 pub(crate) use std::any::{Any, TypeId, type_name};
-pub(crate) use std::collections::HashMap;
+pub(crate) use std::cell::RefCell;
+pub(crate) use std::collections::{BTreeMap, HashMap, HashSet};
+pub(crate) use std::env::var;
+#[cfg(feature = "config")]
+pub(crate) use std::env::vars_os;
 pub(crate) use std::error::Error as StdError;
+pub(crate) use std::fmt::{Display, Formatter, Result as FmtResult};
+#[cfg(feature = "config")]
+pub(crate) use std::fs::{metadata, read_to_string};
 pub(crate) use std::future::Future;
+pub(crate) use std::hash::Hash;
+pub(crate) use std::marker::PhantomData;
 #[cfg(feature = "traits")]
 pub(crate) use std::marker::Unsize;
+pub(crate) use std::mem::take;
+pub(crate) use std::ops::Deref;
+#[cfg(feature = "pool")]
+pub(crate) use std::ops::DerefMut;
+#[cfg(feature = "config")]
+pub(crate) use std::path::{Path, PathBuf};
 pub(crate) use std::pin::Pin;
-pub(crate) use std::sync::atomic::{AtomicBool, Ordering};
-pub(crate) use std::sync::{Arc, Mutex};
+#[cfg(feature = "jobs")]
+pub(crate) use std::str::FromStr;
+#[cfg(any(feature = "config", feature = "traits"))]
+pub(crate) use std::sync::RwLock;
+#[cfg(any(test, feature = "config"))]
+pub(crate) use std::sync::atomic::AtomicBool;
+pub(crate) use std::sync::atomic::{AtomicUsize, Ordering};
+pub(crate) use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+pub(crate) use std::task::{Context as TaskContext, Poll};
+#[cfg(any(feature = "config", feature = "ttl"))]
+pub(crate) use std::thread;
+#[cfg(any(
+    feature = "config",
+    feature = "health",
+    feature = "hosting",
+    feature = "pool",
+    feature = "ttl"
+))]
+pub(crate) use std::time::Duration;
+#[cfg(any(feature = "health", feature = "hosting", feature = "ttl"))]
+pub(crate) use std::time::Instant;
+#[cfg(feature = "config")]
+pub(crate) use std::time::SystemTime;
 pub(crate) use studiole_report::prelude::*;
 pub(crate) use thiserror::Error;
+#[cfg(feature = "ttl")]
+pub(crate) use tokio::runtime::Handle as RuntimeHandle;
+#[cfg(feature = "hosting")]
+pub(crate) use tokio::select;
+#[cfg(feature = "hosting")]
+pub(crate) use tokio::signal::ctrl_c;
+#[cfg(all(feature = "hosting", unix))]
+pub(crate) use tokio::signal::unix::{SignalKind, signal};
+#[cfg(feature = "hosting")]
+pub(crate) use tokio::sync::watch::{Sender as WatchSender, channel as watch_channel};
+#[cfg(feature = "pool")]
+pub(crate) use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+#[cfg(feature = "health")]
+pub(crate) use tokio::task::spawn_blocking;
+#[cfg(feature = "jobs")]
+pub(crate) use tokio::task::{JoinHandle, JoinSet, spawn};
+#[cfg(any(feature = "health", feature = "hosting", feature = "pool"))]
+pub(crate) use tokio::time::timeout;
+#[cfg(feature = "jobs")]
+pub(crate) use tokio::time::{Instant as TokioInstant, sleep_until};
 pub(crate) use tracing::trace;
+#[cfg(any(feature = "config", feature = "jobs", feature = "ttl"))]
+pub(crate) use tracing::warn;
+#[cfg(feature = "jobs")]
+pub(crate) use tracing::{Instrument, info_span};
@@ -5,7 +5,7 @@ use crate::prelude::*;
 pub type FactoryResult = Result<Arc<dyn Any + Send + Sync>, Report<ResolveError>>;
 
 /// Sync factory closure.
-pub type SyncFactory = Box<dyn Fn(&ServiceProvider) -> FactoryResult + Send + Sync>;
+pub type SyncFactory = Arc<dyn Fn(&ServiceProvider) -> FactoryResult + Send + Sync>;
 
 /// Pinned, boxed, sendable future.
 pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
@@ -16,8 +16,19 @@ pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 /// returned future must be `Send + 'static`, so it cannot borrow from the
 /// caller's stack. Since `ServiceProvider` is a cheap `Arc` clone this has
 /// negligible cost.
-pub type AsyncFactory = Box<dyn Fn(ServiceProvider) -> BoxFuture<FactoryResult> + Send + Sync>;
+pub type AsyncFactory = Arc<dyn Fn(ServiceProvider) -> BoxFuture<FactoryResult> + Send + Sync>;
 
 /// Closure that initializes a service.
 pub(crate) type InitFn =
-    Box<dyn Fn(&ServiceProvider) -> Result<(), Report<InitError>> + Send + Sync>;
+    Arc<dyn Fn(&ServiceProvider) -> Result<(), Report<InitError>> + Send + Sync>;
+
+/// Closure that undoes the initialization of a service.
+pub(crate) type DeinitFn = InitFn;
+
+/// Closure that disposes a type-erased instance.
+pub(crate) type DisposeFn = Arc<dyn Fn(Arc<dyn Any + Send + Sync>) + Send + Sync>;
+
+/// Closure that initializes a service asynchronously.
+#[cfg(feature = "async")]
+pub(crate) type AsyncInitFn =
+    Arc<dyn Fn(ServiceProvider) -> BoxFuture<Result<(), Report<InitError>>> + Send + Sync>;
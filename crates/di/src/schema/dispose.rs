@@ -0,0 +1,10 @@
+//! Service disposal trait.
+
+/// Release the resources held by a service when the container discards it.
+///
+/// Registered via [`ServiceBuilder::with_dispose`](crate::prelude::ServiceBuilder::with_dispose).
+/// Disposal cannot fail, so implementations should log rather than return errors.
+pub trait Dispose: Send + Sync + 'static {
+    /// Release the resources held by the service.
+    fn dispose(&self);
+}
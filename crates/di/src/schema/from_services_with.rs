@@ -0,0 +1,19 @@
+//! Service construction trait with runtime arguments.
+use crate::prelude::*;
+
+/// Create an instance from caller-supplied arguments and dependencies resolved from
+/// the [`ServiceProvider`].
+///
+/// `Args` is usually a tuple. Construct with [`ServiceProvider::get_with`].
+pub trait FromServicesWith<Args>: Send + Sync + 'static {
+    /// Error type returned by [`FromServicesWith::from_services_with`].
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Create an instance from the arguments and the [`ServiceProvider`].
+    fn from_services_with(
+        services: &ServiceProvider,
+        args: Args,
+    ) -> Result<Self, Report<Self::Error>>
+    where
+        Self: Sized;
+}
@@ -7,13 +7,53 @@ pub trait Init: Send + Sync + 'static {
     fn init(&self, services: &ServiceProvider) -> Result<(), Report<InitError>>;
 }
 
+/// Undo a completed initialization.
+///
+/// Registered via [`ServiceBuilder::with_deinit`] and called when
+/// [`InitMode::Rollback`] rolls back a successful init step.
+pub trait Deinit: Send + Sync + 'static {
+    /// Undo the initialization of the service.
+    fn deinit(&self, services: &ServiceProvider) -> Result<(), Report<InitError>>;
+}
+
+/// Control how [`ServiceProvider::init`] handles a failing init step.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum InitMode {
+    /// Stop at the first failing step.
+    ///
+    /// Steps that already succeeded stay initialized and the provider cannot be
+    /// initialized again.
+    #[default]
+    FailFast,
+    /// Run every step whose prerequisites succeeded, then roll back on failure.
+    ///
+    /// - Every failure is collected into a single [`InitError::Aggregate`] report,
+    ///   with the first failure as its source
+    /// - Steps that succeeded are rolled back in reverse order via [`Deinit`]
+    /// - Singletons of the steps that ran are evicted from the cache
+    /// - The provider is left uninitialized so init can be retried with fresh
+    ///   instances
+    Rollback,
+}
+
 /// Errors returned by [`Init`] implementations.
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
 pub enum InitError {
     /// The initialization step failed.
     #[error("Service initialization failed")]
     Init,
-    /// [`ServiceProvider::init`] was called more than once.
+    /// [`ServiceProvider::init`] was called when the [`Lifecycle`] is not
+    /// [`Built`](Lifecycle::Built), such as a second time or after shutdown.
     #[error("Services already initialized")]
     AlreadyInitialized,
+    /// One or more init steps failed in [`InitMode::Rollback`].
+    #[error("One or more init steps failed")]
+    Aggregate,
+    /// The ordering constraints between init steps form a cycle.
+    #[error("Init steps have cyclic ordering constraints")]
+    Cycle,
+    /// An init step requires async initialization but [`ServiceProvider::init`] was called.
+    #[cfg(feature = "async")]
+    #[error("Service requires async initialization")]
+    Async,
 }
@@ -0,0 +1,279 @@
+//! Registered initialization steps and their ordering.
+use crate::prelude::*;
+
+/// Internal init step pairing a service type with its init closure.
+#[derive(Clone)]
+pub(crate) struct InitStep {
+    /// Type of the initialized service.
+    pub type_id: TypeId,
+    /// Name of the initialized type, used for diagnostics.
+    pub type_name: &'static str,
+    /// Closure that resolves and initializes the service.
+    pub run: InitRun,
+}
+
+/// Init closure for a sync or async step.
+#[derive(Clone)]
+pub(crate) enum InitRun {
+    /// Step registered via [`ServiceBuilder::with_init`].
+    Sync(InitFn),
+    /// Step registered via `ServiceBuilder::with_init_async`.
+    #[cfg(feature = "async")]
+    Async(AsyncInitFn),
+}
+
+/// Ordering constraint requiring one init step to complete before another starts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct InitConstraint {
+    /// Type whose init step runs first.
+    pub before: TypeId,
+    /// Type whose init step runs second.
+    pub after: TypeId,
+}
+
+/// Init steps sorted into waves that respect the ordering constraints.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct InitPlan {
+    /// Step indexes grouped into waves.
+    ///
+    /// Every step in a wave depends only on steps in earlier waves, so the steps
+    /// within a wave may run concurrently.
+    pub waves: Vec<Vec<usize>>,
+    /// Indexes of the steps that must complete before each step.
+    pub prerequisites: Vec<Vec<usize>>,
+}
+
+impl InitPlan {
+    /// Iterate step indexes in a sequential order that respects the constraints.
+    pub fn order(&self) -> impl Iterator<Item = usize> + '_ {
+        self.waves.iter().flatten().copied()
+    }
+}
+
+/// Sort init steps into an [`InitPlan`].
+///
+/// - Steps without constraints keep their registration order
+/// - Constraints referring to a type without an init step are ignored
+///
+/// Returns [`InitError::Cycle`] if the constraints form a cycle.
+pub(crate) fn sort_init_steps(
+    steps: &[InitStep],
+    constraints: &[InitConstraint],
+) -> Result<InitPlan, Report<InitError>> {
+    let mut indexes: HashMap<TypeId, Vec<usize>> = HashMap::new();
+    for (index, step) in steps.iter().enumerate() {
+        indexes.entry(step.type_id).or_default().push(index);
+    }
+    let mut nodes: Vec<SortNode> = steps.iter().map(|_| SortNode::default()).collect();
+    let mut prerequisites: Vec<Vec<usize>> = vec![Vec::new(); steps.len()];
+    for constraint in constraints {
+        let (Some(befores), Some(afters)) = (
+            indexes.get(&constraint.before),
+            indexes.get(&constraint.after),
+        ) else {
+            continue;
+        };
+        for &before in befores {
+            for &after in afters {
+                if let Some(node) = nodes.get_mut(before) {
+                    node.dependents.push(after);
+                }
+                if let Some(node) = nodes.get_mut(after) {
+                    node.pending += 1;
+                }
+                if let Some(list) = prerequisites.get_mut(after) {
+                    list.push(before);
+                }
+            }
+        }
+    }
+    let mut waves = Vec::new();
+    loop {
+        let wave: Vec<usize> = nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !node.done && node.pending == 0)
+            .map(|(index, _)| index)
+            .collect();
+        if wave.is_empty() {
+            break;
+        }
+        let mut released = Vec::new();
+        for &index in &wave {
+            if let Some(node) = nodes.get_mut(index) {
+                node.done = true;
+                released.extend(node.dependents.iter().copied());
+            }
+        }
+        for index in released {
+            if let Some(node) = nodes.get_mut(index) {
+                node.pending -= 1;
+            }
+        }
+        waves.push(wave);
+    }
+    if nodes.iter().all(|node| node.done) {
+        return Ok(InitPlan {
+            waves,
+            prerequisites,
+        });
+    }
+    let mut report = Report::new(InitError::Cycle);
+    for (step, _) in steps.iter().zip(&nodes).filter(|(_, node)| !node.done) {
+        report = report.attach("type", step.type_name);
+    }
+    Err(report)
+}
+
+/// Outcome of each step during an [`InitMode::Rollback`] run.
+pub(crate) struct InitProgress<'a> {
+    /// Plan being executed.
+    plan: &'a InitPlan,
+    /// Indexes of the steps that succeeded, in completion order.
+    completed: Vec<usize>,
+    /// Indexes of the steps that failed, with their errors.
+    failed: Vec<(usize, Report<InitError>)>,
+    /// Indexes of the steps that were not run because a prerequisite did not succeed.
+    skipped: Vec<usize>,
+}
+
+impl<'a> InitProgress<'a> {
+    /// Create an empty progress tracker for a plan.
+    pub fn new(plan: &'a InitPlan) -> Self {
+        Self {
+            plan,
+            completed: Vec::new(),
+            failed: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Check whether every prerequisite of a step succeeded.
+    ///
+    /// Steps that cannot run are recorded as skipped.
+    pub fn is_runnable(&mut self, index: usize) -> bool {
+        let runnable = self
+            .plan
+            .prerequisites
+            .get(index)
+            .is_none_or(|list| list.iter().all(|before| self.completed.contains(before)));
+        if !runnable {
+            self.skipped.push(index);
+        }
+        runnable
+    }
+
+    /// Record the result of running a step.
+    pub fn record(&mut self, index: usize, result: Result<(), Report<InitError>>) {
+        match result {
+            Ok(()) => self.completed.push(index),
+            Err(error) => self.failed.push((index, error)),
+        }
+    }
+
+    /// Roll back the completed steps if any step failed.
+    ///
+    /// - Completed steps are rolled back in reverse order via their [`Deinit`] hook,
+    ///   if one is registered
+    /// - Cached singletons of the completed and failed steps are evicted so a
+    ///   retried init constructs them afresh
+    /// - Returns [`InitError::Aggregate`] with the first failure as its source, and
+    ///   every other failed, skipped and rolled back step attached
+    pub fn finish(
+        self,
+        services: &ServiceProvider,
+        steps: &[InitStep],
+    ) -> Result<(), Report<InitError>> {
+        let Self {
+            completed,
+            failed,
+            skipped,
+            ..
+        } = self;
+        let mut failed = failed
+            .into_iter()
+            .filter_map(|(index, error)| steps.get(index).map(|step| (step, error)));
+        let Some((first, error)) = failed.next() else {
+            return Ok(());
+        };
+        let mut evicted = vec![first.type_id];
+        let mut report = error
+            .change_context(InitError::Aggregate)
+            .attach("failed", first.type_name);
+        for (step, error) in failed {
+            evicted.push(step.type_id);
+            report = report.attach("failed", format!("{}: {error:?}", step.type_name));
+        }
+        for step in skipped.iter().filter_map(|&index| steps.get(index)) {
+            report = report.attach("skipped", step.type_name);
+        }
+        for step in completed.iter().rev().filter_map(|&index| steps.get(index)) {
+            evicted.push(step.type_id);
+            let Some(deinit_fn) = services.registry.deinits.get(&step.type_id) else {
+                continue;
+            };
+            report = match deinit_fn(services) {
+                Ok(()) => report.attach("rolled back", step.type_name),
+                Err(error) => {
+                    report.attach("rollback failed", format!("{}: {error:?}", step.type_name))
+                }
+            };
+        }
+        services.evict(&evicted);
+        Err(report)
+    }
+}
+
+/// Working state for a single step while sorting.
+#[derive(Default)]
+struct SortNode {
+    /// Steps that must wait for this step.
+    dependents: Vec<usize>,
+    /// Number of constraints not yet satisfied.
+    pending: usize,
+    /// Whether the step has been assigned to a wave.
+    done: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_init_steps_groups_independent_steps() {
+        // Arrange
+        let steps = vec![step::<u8>(), step::<u16>(), step::<u32>()];
+        let constraints = vec![constraint::<u8, u32>()];
+        // Act
+        let plan = sort_init_steps(&steps, &constraints).expect("should sort");
+        // Assert
+        assert_eq!(plan.waves, vec![vec![0, 1], vec![2]]);
+        assert_eq!(plan.prerequisites, vec![vec![], vec![], vec![0]]);
+    }
+
+    #[test]
+    fn sort_init_steps_ignores_unknown_types() {
+        // Arrange
+        let steps = vec![step::<u8>(), step::<u16>()];
+        let constraints = vec![constraint::<u64, u8>(), constraint::<u16, u64>()];
+        // Act
+        let plan = sort_init_steps(&steps, &constraints).expect("should sort");
+        // Assert
+        assert_eq!(plan.waves, vec![vec![0, 1]]);
+    }
+
+    fn step<T: 'static>() -> InitStep {
+        InitStep {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            run: InitRun::Sync(Arc::new(|_services| Ok(()))),
+        }
+    }
+
+    fn constraint<Before: 'static, After: 'static>() -> InitConstraint {
+        InitConstraint {
+            before: TypeId::of::<Before>(),
+            after: TypeId::of::<After>(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Provider lifecycle states.
+use crate::prelude::*;
+
+/// Lifecycle state of a [`ServiceProvider`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Lifecycle {
+    /// Built but [`ServiceProvider::init`] has not been called.
+    Built,
+    /// Init steps are running, or a [`InitMode::FailFast`] init failed.
+    Initializing,
+    /// Every init step completed.
+    Ready,
+    /// [`ServiceProvider::shutdown`] is running.
+    ShuttingDown,
+    /// [`ServiceProvider::shutdown`] completed.
+    Disposed,
+}
+
+impl Display for Lifecycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Built => "built",
+            Self::Initializing => "initializing",
+            Self::Ready => "ready",
+            Self::ShuttingDown => "shutting down",
+            Self::Disposed => "disposed",
+        };
+        f.write_str(name)
+    }
+}
@@ -1,9 +1,13 @@
 //! Core types and traits for the DI container.
 mod aliases;
+mod dispose;
 mod from_services;
+mod from_services_with;
 mod init;
+mod init_step;
+mod lifecycle;
 mod registration;
-mod scope;
+mod service_descriptor;
 mod service_registry;
 #[cfg(test)]
 mod test_cache;
@@ -11,10 +15,14 @@ mod test_cache;
 mod test_services;
 
 pub(crate) use aliases::*;
+pub use dispose::*;
 pub use from_services::*;
+pub use from_services_with::*;
 pub use init::*;
+pub(crate) use init_step::*;
+pub use lifecycle::*;
 pub(crate) use registration::*;
-pub(crate) use scope::*;
+pub use service_descriptor::*;
 pub(crate) use service_registry::*;
 #[cfg(test)]
 pub(crate) use test_cache::*;
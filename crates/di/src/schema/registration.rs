@@ -1,16 +1,19 @@
 //! Service registration entry.
 use crate::prelude::*;
 
-/// Internal registration entry pairing a scope with a factory.
+/// Internal registration entry pairing a lifetime with a factory.
+#[derive(Clone)]
 pub(crate) struct Registration {
+    /// Name of the registered type, used for diagnostics.
+    pub type_name: &'static str,
     /// Caching strategy for this service.
-    pub scope: Scope,
+    pub lifetime: Arc<dyn Lifetime>,
+    /// Whether the service is constructed up front rather than on first resolution.
+    pub eager: bool,
     /// Whether the service requires async resolution.
-    #[cfg_attr(
-        not(feature = "async"),
-        expect(dead_code, reason = "used when async feature is enabled")
-    )]
     pub is_async: bool,
+    /// Profile that contributed the registration, or `None` if unconditional.
+    pub profile: Option<String>,
     /// Sync factory closure that constructs the service.
     pub factory: SyncFactory,
     /// Async factory closure, present only for async registrations.
@@ -20,3 +23,27 @@ pub(crate) struct Registration {
     )]
     pub async_factory: Option<AsyncFactory>,
 }
+
+/// Internal registration entry for a service cached per argument value.
+#[derive(Clone)]
+pub(crate) struct KeyedRegistration {
+    /// Name of the registered type, used for diagnostics.
+    pub type_name: &'static str,
+    /// Type of the argument the instances are keyed by.
+    pub key_type_id: TypeId,
+    /// Maximum number of cached instances, or `None` if unbounded.
+    pub capacity: Option<usize>,
+    /// Profile that contributed the registration, or `None` if unconditional.
+    pub profile: Option<String>,
+}
+
+/// Internal registration entry for a pre-built singleton instance.
+#[derive(Clone)]
+pub(crate) struct InstanceRegistration {
+    /// Name of the registered type, used for diagnostics.
+    pub type_name: &'static str,
+    /// Profile that contributed the registration, or `None` if unconditional.
+    pub profile: Option<String>,
+    /// The pre-built instance.
+    pub value: Arc<dyn Any + Send + Sync>,
+}
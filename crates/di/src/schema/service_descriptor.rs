@@ -0,0 +1,75 @@
+//! Registration introspection types.
+use crate::prelude::*;
+
+/// Description of a registered service, returned by [`ServiceProvider::descriptors`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServiceDescriptor {
+    /// Name of the registered type.
+    pub type_name: &'static str,
+    /// How the service is constructed and cached.
+    pub lifetime: ServiceLifetime,
+    /// Whether the service requires async resolution.
+    pub is_async: bool,
+    /// Whether the service is constructed up front rather than on first resolution.
+    pub eager: bool,
+    /// Profile that contributed the registration, or `None` if unconditional.
+    pub profile: Option<String>,
+    /// Current branch of a trait switch, or `None` for other registrations.
+    pub branch: Option<&'static str>,
+    /// Number of parent hops to the provider that serves the type.
+    ///
+    /// `0` for the provider that was asked, `1` for its parent, and so on.
+    pub level: usize,
+    /// Snapshot of the pool of a pooled service, or `None` for other registrations.
+    #[cfg(feature = "pool")]
+    pub pool: Option<PoolStats>,
+}
+
+/// How a registered service is constructed and cached.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceLifetime {
+    /// Pre-built instance registered with [`ServiceBuilder::with_instance`].
+    Instance,
+    /// Constructed once and shared.
+    Singleton,
+    /// Constructed on every resolution.
+    Transient,
+    /// Shared while in use and constructed afresh after release, registered with
+    /// [`ServiceBuilder::with_type_weak`].
+    Weak,
+    /// Constructed once per thread, registered with
+    /// [`ServiceBuilder::with_type_thread_local`].
+    ThreadLocal,
+    /// Cached until expired, registered with `ServiceBuilder::with_type_ttl`.
+    #[cfg(feature = "ttl")]
+    Ttl,
+    /// Borrowed from a pool and reused, registered with
+    /// `ServiceBuilder::with_type_pooled`.
+    #[cfg(feature = "pool")]
+    Pooled,
+    /// Constructed once per argument value, registered with
+    /// [`ServiceBuilder::with_keyed`] or [`ServiceBuilder::with_keyed_lru`].
+    Keyed,
+    /// Cached by a custom [`Lifetime`], registered with
+    /// [`ServiceBuilder::with_type_lifetime`].
+    Custom(&'static str),
+}
+
+impl Display for ServiceLifetime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Instance => "instance",
+            Self::Singleton => "singleton",
+            Self::Transient => "transient",
+            Self::Weak => "weak",
+            Self::ThreadLocal => "thread-local",
+            #[cfg(feature = "ttl")]
+            Self::Ttl => "ttl",
+            #[cfg(feature = "pool")]
+            Self::Pooled => "pooled",
+            Self::Keyed => "keyed",
+            Self::Custom(name) => name,
+        };
+        f.write_str(name)
+    }
+}
@@ -7,11 +7,146 @@ use crate::prelude::*;
 /// take ownership of a cloned provider without borrowing from the caller's stack.
 pub(crate) struct ServiceRegistry {
     /// Factory registrations keyed by type.
-    pub factories: HashMap<TypeId, Registration>,
+    ///
+    /// Shared with forks of the provider.
+    pub factories: Arc<HashMap<TypeId, Registration>>,
+    /// Types of the factory registrations in registration order.
+    ///
+    /// Shared with forks of the provider.
+    pub order: Arc<Vec<TypeId>>,
     /// Cached singleton instances keyed by type.
     pub instances: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
-    /// Ordered init closures to run during [`ServiceProvider::init`].
-    pub inits: Vec<InitFn>,
-    /// Whether [`ServiceProvider::init`] has been called.
-    pub initialized: AtomicBool,
+    /// Pre-built singleton instances keyed by type.
+    pub instance_registrations: HashMap<TypeId, InstanceRegistration>,
+    /// Weak references to instances of weak registrations keyed by type.
+    pub weak_instances: Mutex<HashMap<TypeId, Weak<dyn Any + Send + Sync>>>,
+    /// Incremented on shutdown to discard instances of thread-local registrations
+    /// cached by other threads.
+    pub thread_local_generation: AtomicUsize,
+    /// Locks held while an async singleton is constructed, keyed by type.
+    #[cfg(feature = "async")]
+    pub constructing: Mutex<HashMap<TypeId, Arc<AsyncMutex<()>>>>,
+    /// Instances of time-to-live registrations keyed by type.
+    #[cfg(feature = "ttl")]
+    pub ttl_instances: Mutex<HashMap<TypeId, TtlEntry>>,
+    /// Registrations cached per argument value, keyed by type.
+    pub keyed: HashMap<TypeId, KeyedRegistration>,
+    /// Caches of instances constructed per argument value, keyed by type.
+    pub keyed_instances: Mutex<HashMap<TypeId, Box<dyn ErasedKeyedCache>>>,
+    /// Registrations borrowed from a pool, keyed by type.
+    #[cfg(feature = "pool")]
+    pub pooled: HashMap<TypeId, PoolRegistration>,
+    /// Pools of pooled registrations keyed by type.
+    #[cfg(feature = "pool")]
+    pub pools: Mutex<HashMap<TypeId, Arc<Pool>>>,
+    /// Active profiles.
+    pub profiles: Vec<String>,
+    /// Ordered init steps to run during [`ServiceProvider::init`].
+    pub inits: Vec<InitStep>,
+    /// Types that have an init step.
+    pub init_types: HashSet<TypeId>,
+    /// Ordering constraints between init steps.
+    pub init_constraints: Vec<InitConstraint>,
+    /// Undo hooks keyed by the type of the init step they roll back.
+    pub deinits: HashMap<TypeId, DeinitFn>,
+    /// Disposal hooks keyed by type.
+    pub disposers: HashMap<TypeId, DisposeFn>,
+    /// Reset hooks of pooled types keyed by type.
+    #[cfg(feature = "pool")]
+    pub resetters: HashMap<TypeId, ResetFn>,
+    /// How a failing init step is handled.
+    pub init_mode: InitMode,
+    /// Current lifecycle state.
+    pub lifecycle: Mutex<Lifecycle>,
+    /// Types whose init step completed, in completion order.
+    pub initialized: Mutex<Vec<TypeId>>,
+    /// Whether resolution is rejected in lifecycle states where it is unsafe.
+    pub strict: bool,
+    /// Provider that resolves types not registered here.
+    pub parent: Option<ServiceProvider>,
+    /// Layered configuration sources.
+    #[cfg(feature = "config")]
+    pub config: ConfigSources,
+    /// Poller shared by every [`OptionsMonitor`], if watching is enabled.
+    #[cfg(feature = "config")]
+    pub config_watcher: Option<Arc<ConfigWatcher>>,
+    /// Branch reporters of trait switches keyed by trait object type.
+    #[cfg(feature = "traits")]
+    pub switches: HashMap<TypeId, BranchFn>,
+    /// Registered health checks in registration order.
+    #[cfg(feature = "health")]
+    pub health_checks: Vec<HealthCheckRegistration>,
+    /// Time allowed for each health check.
+    #[cfg(feature = "health")]
+    pub health_check_timeout: Option<Duration>,
+    /// Hosted services in registration order.
+    #[cfg(feature = "hosting")]
+    pub hosted: Vec<HostedRegistration>,
+    /// Scheduled jobs in registration order.
+    #[cfg(feature = "jobs")]
+    pub jobs: Vec<JobRegistration>,
+}
+
+impl ServiceRegistry {
+    /// Create a registry sharing the registrations of this one with a fresh cache.
+    ///
+    /// The cache is seeded with the pre-built instances and the lifecycle starts at
+    /// [`Lifecycle::Built`].
+    pub fn fork(&self) -> Self {
+        Self {
+            factories: self.factories.clone(),
+            order: self.order.clone(),
+            instances: Mutex::new(seed_instances(&self.instance_registrations)),
+            instance_registrations: self.instance_registrations.clone(),
+            weak_instances: Mutex::new(HashMap::new()),
+            thread_local_generation: AtomicUsize::new(0),
+            #[cfg(feature = "async")]
+            constructing: Mutex::new(HashMap::new()),
+            #[cfg(feature = "ttl")]
+            ttl_instances: Mutex::new(HashMap::new()),
+            keyed: self.keyed.clone(),
+            keyed_instances: Mutex::new(HashMap::new()),
+            #[cfg(feature = "pool")]
+            pooled: self.pooled.clone(),
+            #[cfg(feature = "pool")]
+            pools: Mutex::new(HashMap::new()),
+            profiles: self.profiles.clone(),
+            inits: self.inits.clone(),
+            init_types: self.init_types.clone(),
+            init_constraints: self.init_constraints.clone(),
+            deinits: self.deinits.clone(),
+            disposers: self.disposers.clone(),
+            #[cfg(feature = "pool")]
+            resetters: self.resetters.clone(),
+            init_mode: self.init_mode,
+            lifecycle: Mutex::new(Lifecycle::Built),
+            initialized: Mutex::new(Vec::new()),
+            strict: self.strict,
+            parent: self.parent.clone(),
+            #[cfg(feature = "config")]
+            config: self.config.clone(),
+            #[cfg(feature = "config")]
+            config_watcher: self.config_watcher.clone(),
+            #[cfg(feature = "traits")]
+            switches: self.switches.clone(),
+            #[cfg(feature = "health")]
+            health_checks: self.health_checks.clone(),
+            #[cfg(feature = "health")]
+            health_check_timeout: self.health_check_timeout,
+            #[cfg(feature = "hosting")]
+            hosted: self.hosted.clone(),
+            #[cfg(feature = "jobs")]
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+/// Create an instance cache holding the pre-built instances.
+pub(crate) fn seed_instances(
+    instances: &HashMap<TypeId, InstanceRegistration>,
+) -> HashMap<TypeId, Arc<dyn Any + Send + Sync>> {
+    instances
+        .iter()
+        .map(|(type_id, instance)| (*type_id, instance.value.clone()))
+        .collect()
 }
@@ -1,6 +1,7 @@
 //! Concrete service fixtures for DI container tests.
 #![allow(dead_code)]
 use crate::prelude::*;
+use std::sync::atomic::AtomicUsize;
 
 /// Minimal configuration for testing.
 pub struct Config {
@@ -86,7 +87,90 @@ pub struct MixedService {
     pub port: u16,
 }
 
+/// Service using derive macro, resolves [`Config`] on first use.
+#[derive(FromServices)]
+pub struct DerivedLazyService {
+    /// Configuration resolved on first use.
+    pub config: Lazy<Config>,
+}
+
+/// Singleton using derive macro, creates a [`CountedService`] per unit of work.
+#[derive(FromServices)]
+pub struct DerivedWorker {
+    /// Factory of counted services.
+    pub services: Factory<CountedService>,
+}
+
+/// Service using derive macro, constructed with a user id and path.
+#[derive(FromServices)]
+pub struct DerivedSession {
+    /// Resolved configuration.
+    pub config: Arc<Config>,
+    /// User id from the arguments.
+    #[di(arg)]
+    pub user_id: u64,
+    /// Path from the arguments.
+    #[di(arg)]
+    pub path: String,
+    /// Default retries.
+    #[di(default)]
+    pub retries: u16,
+}
+
+/// Async service using derive macro, constructed with a user id.
+#[cfg(feature = "async")]
+#[derive(FromServicesAsync)]
+pub struct DerivedAsyncSession {
+    /// Resolved database.
+    pub db: Arc<AsyncDatabase>,
+    /// User id from the arguments.
+    #[di(arg)]
+    pub user_id: u64,
+}
+
 /// Async unit struct service for testing.
 #[cfg(feature = "async")]
 #[derive(FromServicesAsync)]
 pub struct AsyncUnitService;
+
+/// Count how many times a service was constructed.
+#[derive(Default)]
+pub struct ConstructionCounter {
+    /// Number of constructions.
+    pub count: AtomicUsize,
+}
+
+impl ConstructionCounter {
+    /// Get the number of constructions.
+    pub fn get(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+/// Service that increments [`ConstructionCounter`] when constructed.
+pub struct CountedService;
+
+impl FromServices for CountedService {
+    type Error = ResolveError;
+
+    fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        let counter = services.get::<ConstructionCounter>()?;
+        counter.count.fetch_add(1, Ordering::SeqCst);
+        Ok(Self)
+    }
+}
+
+/// Async service that increments [`ConstructionCounter`] when constructed.
+#[cfg(feature = "async")]
+pub struct AsyncCountedService;
+
+#[cfg(feature = "async")]
+impl FromServicesAsync for AsyncCountedService {
+    type Error = ResolveError;
+
+    async fn from_services_async(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        let counter = services.get::<ConstructionCounter>()?;
+        counter.count.fetch_add(1, Ordering::SeqCst);
+        Ok(Self)
+    }
+}
@@ -0,0 +1,7 @@
+//! Ambient service scopes.
+mod scoped_future;
+mod service_provider_scope;
+mod service_scope;
+
+pub use scoped_future::*;
+pub use service_scope::*;
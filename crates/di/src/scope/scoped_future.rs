@@ -0,0 +1,30 @@
+//! Future running within a service scope.
+use crate::prelude::*;
+
+/// Future that installs a [`ServiceScope`] each time it is polled.
+///
+/// Returned by [`ServiceScope::run`].
+pub struct ScopedFuture<F> {
+    scope: ServiceScope,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> ScopedFuture<F> {
+    /// Wrap `future` so it runs within `scope`.
+    pub(crate) fn new(scope: ServiceScope, future: F) -> Self {
+        Self {
+            scope,
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for ScopedFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<F::Output> {
+        let this = self.get_mut();
+        let _guard = this.scope.enter();
+        this.future.as_mut().poll(cx)
+    }
+}
@@ -0,0 +1,35 @@
+//! Ambient provider access.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Get the provider of the innermost [`ServiceScope`] installed on this thread or
+    /// task.
+    ///
+    /// Returns `None` outside of any scope. Intended for code that cannot take a
+    /// provider parameter, such as callbacks with fixed signatures.
+    #[must_use]
+    pub fn current() -> Option<ServiceProvider> {
+        ServiceScope::current()
+    }
+
+    /// Create a scope that resolves services from this provider.
+    ///
+    /// The scope is a [`child`](ServiceProvider::child) without registrations of its
+    /// own.
+    #[must_use]
+    pub fn scope(&self) -> ServiceScope {
+        self.scope_with(|builder| builder)
+    }
+
+    /// Create a scope with its own registrations that falls back to this provider.
+    ///
+    /// `configure` receives an empty [`ServiceBuilder`] as in
+    /// [`child`](ServiceProvider::child).
+    #[must_use]
+    pub fn scope_with(
+        &self,
+        configure: impl FnOnce(ServiceBuilder) -> ServiceBuilder,
+    ) -> ServiceScope {
+        ServiceScope::from(self.child(configure))
+    }
+}
@@ -0,0 +1,206 @@
+//! Service scope installed as the current provider.
+use crate::prelude::*;
+
+thread_local! {
+    /// Provider of the innermost [`ServiceScope`] installed on this thread.
+    static CURRENT: RefCell<Option<ServiceProvider>> = const { RefCell::new(None) };
+}
+
+/// Provider that can be installed as the current provider of a thread or task.
+///
+/// Created by [`ServiceProvider::scope`] or [`ServiceProvider::scope_with`], or from
+/// any provider with [`From`]. While a scope is installed
+/// [`ServiceProvider::current`] returns its provider, so code without a provider
+/// parameter can still resolve services.
+///
+/// ```ignore
+/// let scope = services.scope_with(|builder| builder.with_instance(RequestId::new()));
+/// scope.run(async { handle_request().await }).await;
+/// ```
+#[derive(Clone)]
+pub struct ServiceScope {
+    services: ServiceProvider,
+}
+
+impl ServiceScope {
+    /// Install the scope on this thread until the guard is dropped.
+    ///
+    /// For synchronous code such as callbacks. The guard restores the previously
+    /// installed scope, so guards must be dropped in the reverse order they were
+    /// created. Use [`run`](ServiceScope::run) to install a scope for async code.
+    #[must_use]
+    pub fn enter(&self) -> ScopeGuard {
+        let previous = CURRENT.replace(Some(self.services.clone()));
+        ScopeGuard {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Install the scope for the duration of a future.
+    ///
+    /// The scope is installed each time the future is polled and the previous scope
+    /// is restored afterwards, so it follows the task between threads and does not
+    /// leak into other tasks polled on the same thread. Scopes may be nested.
+    pub fn run<F: Future>(&self, future: F) -> ScopedFuture<F> {
+        ScopedFuture::new(self.clone(), future)
+    }
+
+    /// Get the provider of the innermost scope installed on this thread.
+    pub(crate) fn current() -> Option<ServiceProvider> {
+        CURRENT.with_borrow(Clone::clone)
+    }
+}
+
+impl Deref for ServiceScope {
+    type Target = ServiceProvider;
+
+    fn deref(&self) -> &ServiceProvider {
+        &self.services
+    }
+}
+
+impl From<ServiceProvider> for ServiceScope {
+    fn from(services: ServiceProvider) -> Self {
+        Self { services }
+    }
+}
+
+/// Restores the previously installed scope when dropped.
+///
+/// Returned by [`ServiceScope::enter`].
+pub struct ScopeGuard {
+    previous: Option<ServiceProvider>,
+    /// The guard must be dropped on the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        let scope = CURRENT.replace(self.previous.take());
+        drop(scope);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::join;
+    use tokio::task::yield_now;
+
+    fn base() -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build()
+    }
+
+    fn is_current(scope: &ServiceScope) -> bool {
+        ServiceProvider::current()
+            .is_some_and(|current| Arc::ptr_eq(&current.registry, &scope.registry))
+    }
+
+    #[test]
+    fn current_is_none_outside_scope() {
+        // Act
+        let current = ServiceProvider::current();
+
+        // Assert
+        assert!(current.is_none());
+    }
+
+    #[test]
+    fn enter_installs_and_restores_nested_scopes() {
+        // Arrange
+        let services = base();
+        let outer = services.scope();
+        let inner = services.scope();
+
+        // Act
+        let outer_guard = outer.enter();
+        let inner_guard = inner.enter();
+        let in_inner = is_current(&inner);
+        drop(inner_guard);
+        let in_outer = is_current(&outer);
+        drop(outer_guard);
+
+        // Assert
+        assert!(in_inner);
+        assert!(in_outer);
+        assert!(ServiceProvider::current().is_none());
+    }
+
+    #[test]
+    fn scope_with_resolves_scoped_and_parent_services() {
+        // Arrange
+        let services = base();
+        let scope = services.scope_with(ServiceBuilder::with_type::<Database>);
+        let _guard = scope.enter();
+
+        // Act
+        let current = ServiceProvider::current().expect("should have current provider");
+
+        // Assert
+        assert_eq!(current.expect::<Database>().config.port, 8080);
+        assert!(services.get::<Database>().is_err());
+    }
+
+    #[tokio::test]
+    async fn run_installs_scope_for_future() {
+        // Arrange
+        let scope = base().scope();
+
+        // Act
+        let during = scope
+            .run(async {
+                yield_now().await;
+                is_current(&scope)
+            })
+            .await;
+
+        // Assert
+        assert!(during);
+        assert!(ServiceProvider::current().is_none());
+    }
+
+    #[tokio::test]
+    async fn run_restores_outer_scope_after_nested() {
+        // Arrange
+        let services = base();
+        let outer = services.scope();
+        let inner = services.scope();
+
+        // Act
+        let (in_inner, in_outer) = outer
+            .run(async {
+                let in_inner = inner.run(async { is_current(&inner) }).await;
+                yield_now().await;
+                (in_inner, is_current(&outer))
+            })
+            .await;
+
+        // Assert
+        assert!(in_inner);
+        assert!(in_outer);
+    }
+
+    #[tokio::test]
+    async fn run_does_not_leak_into_other_tasks() {
+        // Arrange
+        let scope = base().scope();
+
+        // Act
+        let ((), outside) = join!(
+            scope.run(async {
+                yield_now().await;
+                yield_now().await;
+            }),
+            async {
+                yield_now().await;
+                ServiceProvider::current().is_none()
+            }
+        );
+
+        // Assert
+        assert!(outside);
+    }
+}
@@ -6,10 +6,54 @@ use crate::prelude::*;
 pub struct ServiceBuilder {
     /// Factory registrations keyed by type.
     pub(crate) factories: HashMap<TypeId, Registration>,
+    /// Types of the factory registrations in registration order.
+    pub(crate) order: Vec<TypeId>,
     /// Pre-built singleton instances keyed by type.
-    pub(crate) instances: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
-    /// Ordered init closures.
-    pub(crate) inits: Vec<InitFn>,
+    pub(crate) instances: HashMap<TypeId, InstanceRegistration>,
+    /// Registrations cached per argument value, keyed by type.
+    pub(crate) keyed: HashMap<TypeId, KeyedRegistration>,
+    /// Registrations borrowed from a pool, keyed by type.
+    #[cfg(feature = "pool")]
+    pub(crate) pooled: HashMap<TypeId, PoolRegistration>,
+    /// Ordered init steps.
+    pub(crate) inits: Vec<InitStep>,
+    /// Ordering constraints between init steps.
+    pub(crate) init_constraints: Vec<InitConstraint>,
+    /// Undo hooks keyed by the type of the init step they roll back.
+    pub(crate) deinits: HashMap<TypeId, DeinitFn>,
+    /// Disposal hooks keyed by type.
+    pub(crate) disposers: HashMap<TypeId, DisposeFn>,
+    /// Reset hooks of pooled types keyed by type.
+    #[cfg(feature = "pool")]
+    pub(crate) resetters: HashMap<TypeId, ResetFn>,
+    /// How a failing init step is handled.
+    pub(crate) init_mode: InitMode,
+    /// Whether resolution is rejected in lifecycle states where it is unsafe.
+    pub(crate) strict: bool,
+    /// Provider that resolves types not registered here.
+    pub(crate) parent: Option<ServiceProvider>,
+    /// Layered configuration sources.
+    #[cfg(feature = "config")]
+    pub(crate) config: ConfigSources,
+    /// Branch reporters of trait switches keyed by trait object type.
+    #[cfg(feature = "traits")]
+    pub(crate) switches: HashMap<TypeId, BranchFn>,
+    /// Whether every singleton is constructed eagerly.
+    pub(crate) eager: bool,
+    /// Registered health checks in registration order.
+    #[cfg(feature = "health")]
+    pub(crate) health_checks: Vec<HealthCheckRegistration>,
+    /// Time allowed for each health check.
+    #[cfg(feature = "health")]
+    pub(crate) health_check_timeout: Option<Duration>,
+    /// Hosted services in registration order.
+    #[cfg(feature = "hosting")]
+    pub(crate) hosted: Vec<HostedRegistration>,
+    /// Scheduled jobs in registration order.
+    #[cfg(feature = "jobs")]
+    pub(crate) jobs: Vec<JobRegistration>,
+    /// Registrations that only apply when their profile is active.
+    pub(crate) profiles: Vec<(String, ServiceBuilder)>,
 }
 
 impl ServiceBuilder {
@@ -23,47 +67,183 @@ impl ServiceBuilder {
     #[must_use]
     pub fn with_instance<T: Send + Sync + 'static>(mut self, value: T) -> Self {
         let type_id = TypeId::of::<T>();
-        let dynamic = value.into_any();
-        self.instances.insert(type_id, dynamic);
+        self.instances.insert(
+            type_id,
+            InstanceRegistration {
+                type_name: type_name::<T>(),
+                profile: None,
+                value: value.into_any(),
+            },
+        );
         self
     }
 
     /// Register a singleton type for resolution via [`FromServices`].
     #[must_use]
     pub fn with_type<T: FromServices>(self) -> Self {
-        self.register_type::<T>(Scope::Singleton)
+        self.register_type::<T>(SingletonLifetime)
     }
 
     /// Register a transient type for resolution via [`FromServices`].
     #[must_use]
     pub fn with_type_transient<T: FromServices>(self) -> Self {
-        self.register_type::<T>(Scope::Transient)
+        self.register_type::<T>(TransientLifetime)
+    }
+
+    /// Register a type that is shared while in use and rebuilt after release.
+    ///
+    /// The provider only holds a weak reference. While any consumer holds the
+    /// instance every resolution returns it, and once every consumer has dropped it
+    /// the next resolution constructs a fresh one.
+    #[must_use]
+    pub fn with_type_weak<T: FromServices>(self) -> Self {
+        self.register_type::<T>(WeakLifetime)
+    }
+
+    /// Register a type that is cached once per thread.
+    ///
+    /// Each thread that resolves the type constructs and caches its own instance,
+    /// which is dropped when the thread exits. Useful for services that are cheap to
+    /// duplicate but costly to share, such as random number generators.
+    ///
+    /// In async code the instance belongs to the thread the task was running on when
+    /// it resolved the type. On a multi-threaded runtime a task may resume on another
+    /// thread after an `.await`, so resolve again rather than relying on an instance
+    /// held across one being local to the current thread.
+    #[must_use]
+    pub fn with_type_thread_local<T: FromServices>(self) -> Self {
+        self.register_type::<T>(ThreadLocalLifetime)
+    }
+
+    /// Register a singleton type that is constructed eagerly.
+    ///
+    /// - Sync registrations are constructed during [`ServiceProvider::init`]
+    /// - All eager registrations, including async, are constructed by `ServiceProvider::warm_up`
+    #[must_use]
+    pub fn with_type_eager<T: FromServices>(self) -> Self {
+        self.register_type::<T>(SingletonLifetime)
+            .mark_eager(TypeId::of::<T>())
+    }
+
+    /// Construct every singleton eagerly rather than on first resolution.
+    ///
+    /// Equivalent to registering each singleton with
+    /// [`with_type_eager`](ServiceBuilder::with_type_eager).
+    #[must_use]
+    pub fn with_eager_singletons(mut self) -> Self {
+        self.eager = true;
+        self
     }
 
     /// Mark a type for initialization during [`ServiceProvider::init`].
     #[must_use]
     pub fn with_init<T: Init>(mut self) -> Self {
-        self.inits.push(Box::new(|services| {
-            let instance = services.get::<T>().change_context(InitError::Init)?;
+        let run: InitFn = Arc::new(|services| {
+            let instance = services
+                .get_unguarded::<T>()
+                .change_context(InitError::Init)?;
             instance.init(services)
-        }));
+        });
+        self.inits.push(InitStep {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            run: InitRun::Sync(run),
+        });
+        self
+    }
+
+    /// Register an undo hook that rolls back the init step of `T`.
+    ///
+    /// Called in reverse order for each step that succeeded when a run in
+    /// [`InitMode::Rollback`] fails.
+    #[must_use]
+    pub fn with_deinit<T: Deinit>(mut self) -> Self {
+        let deinit: DeinitFn = Arc::new(|services| {
+            let instance = services
+                .get_unguarded::<T>()
+                .change_context(InitError::Init)?;
+            instance.deinit(services)
+        });
+        self.deinits.insert(TypeId::of::<T>(), deinit);
+        self
+    }
+
+    /// Register a hook that disposes instances of `T` discarded by the container.
+    ///
+    /// Called when an instance registered with
+    /// [`with_keyed_lru`](ServiceBuilder::with_keyed_lru) is evicted, and for every
+    /// cached keyed instance when the provider shuts down. Pooled instances are
+    /// disposed when the provider shuts down, or when handed back afterwards. Callers
+    /// may still hold the disposed instance.
+    #[must_use]
+    pub fn with_dispose<T: Dispose>(mut self) -> Self {
+        let dispose: DisposeFn = Arc::new(|dynamic| dynamic.expect_downcast::<T>().dispose());
+        self.disposers.insert(TypeId::of::<T>(), dispose);
+        self
+    }
+
+    /// Set how [`ServiceProvider::init`] handles a failing init step.
+    #[must_use]
+    pub fn with_init_mode(mut self, mode: InitMode) -> Self {
+        self.init_mode = mode;
+        self
+    }
+
+    /// Reject resolution in lifecycle states where it is unsafe.
+    ///
+    /// - Resolving a service with an init step returns [`ResolveError::NotReady`]
+    ///   until its init step has completed
+    /// - Any resolution after [`ServiceProvider::shutdown`] has begun returns
+    ///   [`ResolveError::ShutDown`]
+    #[must_use]
+    pub fn with_strict_lifecycle(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Require the init step of `T` to run after the init step of `Other`.
+    ///
+    /// - Constraints may be declared before or after the init steps are registered
+    /// - Constraints referring to a type without an init step are ignored
+    #[must_use]
+    pub fn with_init_after<T: 'static, Other: 'static>(mut self) -> Self {
+        self.init_constraints.push(InitConstraint {
+            before: TypeId::of::<Other>(),
+            after: TypeId::of::<T>(),
+        });
+        self
+    }
+
+    /// Require the init step of `T` to run before the init step of `Other`.
+    ///
+    /// - Constraints may be declared before or after the init steps are registered
+    /// - Constraints referring to a type without an init step are ignored
+    #[must_use]
+    pub fn with_init_before<T: 'static, Other: 'static>(mut self) -> Self {
+        self.init_constraints.push(InitConstraint {
+            before: TypeId::of::<T>(),
+            after: TypeId::of::<Other>(),
+        });
         self
     }
 
-    /// Register a type with the given scope.
-    pub(crate) fn register_type<T: FromServices>(mut self, scope: Scope) -> Self {
+    /// Register a type with the given lifetime.
+    pub(crate) fn register_type<T: FromServices>(mut self, lifetime: impl Lifetime) -> Self {
         let type_id = TypeId::of::<T>();
-        let factory: SyncFactory = Box::new(|services: &ServiceProvider| {
+        let factory: SyncFactory = Arc::new(|services: &ServiceProvider| {
             let instance = T::from_services(services)
                 .change_context(ResolveError::Factory)
                 .attach("type", type_name::<T>())?;
             Ok(instance.into_any())
         });
-        self.factories.insert(
+        self.insert_factory(
             type_id,
             Registration {
-                scope,
+                type_name: type_name::<T>(),
+                lifetime: Arc::new(lifetime),
+                eager: false,
                 is_async: false,
+                profile: None,
                 factory,
                 async_factory: None,
             },
@@ -71,15 +251,92 @@ impl ServiceBuilder {
         self
     }
 
+    /// Insert a factory registration, replacing any registration of the same type.
+    ///
+    /// A replaced registration keeps its position in the registration order.
+    pub(crate) fn insert_factory(&mut self, type_id: TypeId, registration: Registration) {
+        if self.factories.insert(type_id, registration).is_none() && !self.order.contains(&type_id)
+        {
+            self.order.push(type_id);
+        }
+    }
+
+    /// Mark an existing registration as eager.
+    pub(crate) fn mark_eager(mut self, type_id: TypeId) -> Self {
+        if let Some(registration) = self.factories.get_mut(&type_id) {
+            registration.eager = true;
+        }
+        self
+    }
+
     /// Build the [`ServiceProvider`] from the registered services.
+    ///
+    /// Registrations added with [`in_profile`](ServiceBuilder::in_profile) are
+    /// ignored. Use [`build_for`](ServiceBuilder::build_for) to activate profiles.
     #[must_use]
     pub fn build(self) -> ServiceProvider {
+        self.build_for(&[])
+    }
+
+    /// Build the [`ServiceProvider`] with the given profiles active.
+    ///
+    /// Registrations from active profiles are applied in the order the profiles
+    /// were added, replacing unconditional registrations of the same type.
+    #[must_use]
+    pub fn build_for(mut self, profiles: &[&str]) -> ServiceProvider {
+        self = self.apply_profiles(profiles);
+        if self.eager {
+            for registration in self.factories.values_mut() {
+                if registration.lifetime.describe() == ServiceLifetime::Singleton {
+                    registration.eager = true;
+                }
+            }
+        }
         ServiceProvider {
             registry: Arc::new(ServiceRegistry {
-                factories: self.factories,
-                instances: Mutex::new(self.instances),
+                factories: Arc::new(self.factories),
+                order: Arc::new(self.order),
+                instances: Mutex::new(seed_instances(&self.instances)),
+                instance_registrations: self.instances,
+                weak_instances: Mutex::new(HashMap::new()),
+                thread_local_generation: AtomicUsize::new(0),
+                #[cfg(feature = "async")]
+                constructing: Mutex::new(HashMap::new()),
+                #[cfg(feature = "ttl")]
+                ttl_instances: Mutex::new(HashMap::new()),
+                keyed: self.keyed,
+                keyed_instances: Mutex::new(HashMap::new()),
+                #[cfg(feature = "pool")]
+                pooled: self.pooled,
+                #[cfg(feature = "pool")]
+                pools: Mutex::new(HashMap::new()),
+                profiles: profiles.iter().map(ToString::to_string).collect(),
+                init_types: self.inits.iter().map(|step| step.type_id).collect(),
                 inits: self.inits,
-                initialized: AtomicBool::new(false),
+                init_constraints: self.init_constraints,
+                deinits: self.deinits,
+                disposers: self.disposers,
+                #[cfg(feature = "pool")]
+                resetters: self.resetters,
+                init_mode: self.init_mode,
+                lifecycle: Mutex::new(Lifecycle::Built),
+                initialized: Mutex::new(Vec::new()),
+                strict: self.strict,
+                parent: self.parent,
+                #[cfg(feature = "config")]
+                config_watcher: ConfigWatcher::new(&self.config),
+                #[cfg(feature = "config")]
+                config: self.config,
+                #[cfg(feature = "traits")]
+                switches: self.switches,
+                #[cfg(feature = "health")]
+                health_checks: self.health_checks,
+                #[cfg(feature = "health")]
+                health_check_timeout: self.health_check_timeout,
+                #[cfg(feature = "hosting")]
+                hosted: self.hosted,
+                #[cfg(feature = "jobs")]
+                jobs: self.jobs,
             }),
         }
     }
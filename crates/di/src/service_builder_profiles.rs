@@ -0,0 +1,409 @@
+//! Profile-aware registration.
+use crate::prelude::*;
+
+/// Separator between profile names in an environment variable.
+const PROFILE_SEPARATOR: char = ',';
+
+impl ServiceBuilder {
+    /// Add registrations that only apply when `profile` is active.
+    ///
+    /// `configure` receives an empty [`ServiceBuilder`] and returns it with the
+    /// profile's registrations. Profiles are activated by
+    /// [`build_for`](ServiceBuilder::build_for) or
+    /// [`build_from_env`](ServiceBuilder::build_from_env).
+    #[must_use]
+    pub fn in_profile(
+        mut self,
+        profile: &str,
+        configure: impl FnOnce(ServiceBuilder) -> ServiceBuilder,
+    ) -> Self {
+        let builder = configure(ServiceBuilder::new());
+        self.profiles.push((profile.to_owned(), builder));
+        self
+    }
+
+    /// Build the [`ServiceProvider`] with the profiles named in an environment variable.
+    ///
+    /// The variable holds a comma separated list such as `dev,local`. If the
+    /// variable is not set no profiles are active.
+    #[must_use]
+    pub fn build_from_env(self, variable: &str) -> ServiceProvider {
+        let value = var(variable).unwrap_or_default();
+        let profiles: Vec<&str> = value
+            .split(PROFILE_SEPARATOR)
+            .map(str::trim)
+            .filter(|profile| !profile.is_empty())
+            .collect();
+        self.build_for(&profiles)
+    }
+
+    /// Merge the registrations of every active profile.
+    pub(crate) fn apply_profiles(mut self, active: &[&str]) -> Self {
+        let profiles = take(&mut self.profiles);
+        for (name, builder) in profiles {
+            if active.contains(&name.as_str()) {
+                trace!(profile = name, "Applying profile");
+                self = self.merge(builder, Some(&name), active);
+            }
+        }
+        self
+    }
+
+    /// Merge another builder's registrations, replacing registrations of the same type.
+    ///
+    /// Registrations are labelled with `profile`, and nested profiles of `other`
+    /// are applied if active.
+    ///
+    /// Init steps, health checks and hosted services of a type that is already
+    /// registered are skipped rather than added twice.
+    pub(crate) fn merge(
+        mut self,
+        mut other: ServiceBuilder,
+        profile: Option<&str>,
+        active: &[&str],
+    ) -> Self {
+        let nested = take(&mut other.profiles);
+        for type_id in other.order {
+            let Some(mut registration) = other.factories.remove(&type_id) else {
+                continue;
+            };
+            registration.profile = profile.map(str::to_owned);
+            self.remove_registrations(type_id);
+            self.insert_factory(type_id, registration);
+        }
+        for (type_id, mut instance) in other.instances {
+            instance.profile = profile.map(str::to_owned);
+            self.remove_registrations(type_id);
+            self.instances.insert(type_id, instance);
+        }
+        for (type_id, mut registration) in other.keyed {
+            registration.profile = profile.map(str::to_owned);
+            self.remove_registrations(type_id);
+            self.keyed.insert(type_id, registration);
+        }
+        #[cfg(feature = "pool")]
+        for (type_id, mut registration) in other.pooled {
+            registration.profile = profile.map(str::to_owned);
+            self.remove_registrations(type_id);
+            self.pooled.insert(type_id, registration);
+        }
+        other
+            .inits
+            .retain(|step| !self.inits.iter().any(|init| init.type_id == step.type_id));
+        self.inits.extend(other.inits);
+        self.init_constraints.extend(other.init_constraints);
+        self.deinits.extend(other.deinits);
+        self.disposers.extend(other.disposers);
+        #[cfg(feature = "pool")]
+        self.resetters.extend(other.resetters);
+        if other.init_mode != InitMode::default() {
+            self.init_mode = other.init_mode;
+        }
+        self.strict |= other.strict;
+        #[cfg(feature = "traits")]
+        self.switches.extend(other.switches);
+        self.eager |= other.eager;
+        #[cfg(feature = "config")]
+        {
+            self.config.files.extend(other.config.files);
+            self.config.env_prefixes.extend(other.config.env_prefixes);
+            self.config.overrides.extend(other.config.overrides);
+            self.config.watch_interval = other.config.watch_interval.or(self.config.watch_interval);
+        }
+        #[cfg(feature = "health")]
+        {
+            other.health_checks.retain(|check| {
+                !self
+                    .health_checks
+                    .iter()
+                    .any(|existing| existing.type_id == check.type_id)
+            });
+            self.health_checks.extend(other.health_checks);
+            self.health_check_timeout = other.health_check_timeout.or(self.health_check_timeout);
+        }
+        #[cfg(feature = "hosting")]
+        {
+            other.hosted.retain(|hosted| {
+                !self
+                    .hosted
+                    .iter()
+                    .any(|existing| existing.type_id == hosted.type_id)
+            });
+            self.hosted.extend(other.hosted);
+        }
+        #[cfg(feature = "jobs")]
+        self.jobs.extend(other.jobs);
+        self.profiles = nested;
+        self.apply_profiles(active)
+    }
+
+    /// Remove every registration of a type so it can be replaced.
+    fn remove_registrations(&mut self, type_id: TypeId) {
+        self.factories.remove(&type_id);
+        self.instances.remove(&type_id);
+        self.keyed.remove(&type_id);
+        #[cfg(feature = "pool")]
+        self.pooled.remove(&type_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Service constructed per tenant name.
+    #[derive(FromServices)]
+    struct Tenant {
+        #[di(arg)]
+        name: String,
+    }
+
+    impl Init for UnitService {
+        fn init(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "health")]
+    impl HealthCheck for UnitService {
+        fn check_health(&self) -> HealthCheckResult {
+            HealthCheckResult::healthy()
+        }
+    }
+
+    #[cfg(feature = "hosting")]
+    impl HostedService for UnitService {
+        async fn start(&self) -> Result<(), Report<HostError>> {
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<(), Report<HostError>> {
+            Ok(())
+        }
+    }
+
+    /// Get the lifetimes of every registration whose type name ends with `name`.
+    fn lifetimes_of(services: &ServiceProvider, name: &str) -> Vec<ServiceLifetime> {
+        services
+            .descriptors()
+            .into_iter()
+            .filter(|descriptor| descriptor.type_name.ends_with(name))
+            .map(|descriptor| descriptor.lifetime)
+            .collect()
+    }
+
+    fn builder() -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .in_profile("test", |builder| {
+                builder
+                    .with_instance(Config { port: 1 })
+                    .with_type::<UnitService>()
+            })
+            .in_profile("local", |builder| builder.with_instance(Config { port: 2 }))
+    }
+
+    #[test]
+    fn build_ignores_profiles() {
+        // Arrange
+        let builder = builder();
+
+        // Act
+        let services = builder.build();
+
+        // Assert
+        assert_eq!(services.expect::<Config>().port, 8080);
+        assert!(services.get::<UnitService>().is_err());
+        assert!(services.profiles().is_empty());
+    }
+
+    #[test]
+    fn build_for_replaces_registrations_from_active_profile() {
+        // Arrange
+        let builder = builder();
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        assert_eq!(services.expect::<Config>().port, 1);
+        assert!(services.get::<UnitService>().is_ok());
+        assert_eq!(services.profiles(), ["test"]);
+    }
+
+    #[test]
+    fn build_for_constructs_eager_profile_singletons_in_registration_order() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .in_profile("test", |builder| {
+                builder
+                    .with_type_eager::<DerivedDatabase>()
+                    .with_type_eager::<Database>()
+                    .with_type_eager::<UnitService>()
+            });
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        let eager: Vec<&str> = services
+            .eager_registrations()
+            .map(|(_, registration)| registration.type_name)
+            .collect();
+        assert_eq!(
+            eager,
+            [
+                type_name::<DerivedDatabase>(),
+                type_name::<Database>(),
+                type_name::<UnitService>()
+            ]
+        );
+    }
+
+    #[test]
+    fn build_for_replaces_instance_with_keyed_registration() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_instance(Tenant {
+                name: "acme".to_owned(),
+            })
+            .in_profile("test", ServiceBuilder::with_keyed::<Tenant, String>);
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        assert_eq!(
+            lifetimes_of(&services, "::Tenant"),
+            [ServiceLifetime::Keyed]
+        );
+        let tenant = services
+            .get_for::<Tenant, _>(&"globex".to_owned())
+            .expect("should resolve");
+        assert_eq!(tenant.name, "globex");
+    }
+
+    #[cfg(feature = "pool")]
+    #[test]
+    fn build_for_replaces_singleton_with_pooled_registration() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_type::<UnitService>()
+            .in_profile("test", |builder| {
+                builder.with_type_pooled::<UnitService>(PoolConfig::new(1))
+            });
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        assert_eq!(
+            lifetimes_of(&services, "::UnitService"),
+            [ServiceLifetime::Pooled]
+        );
+    }
+
+    #[cfg(feature = "hosting")]
+    #[test]
+    fn build_for_registers_hosted_service_once() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_type::<UnitService>()
+            .with_hosted::<UnitService>()
+            .in_profile("test", ServiceBuilder::with_hosted::<UnitService>);
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        assert_eq!(services.registry.hosted.len(), 1);
+    }
+
+    #[test]
+    fn build_for_registers_init_step_once() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_type::<UnitService>()
+            .with_init::<UnitService>()
+            .in_profile("test", ServiceBuilder::with_init::<UnitService>);
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        assert_eq!(services.registry.inits.len(), 1);
+    }
+
+    #[cfg(feature = "health")]
+    #[tokio::test]
+    async fn build_for_registers_health_check_once() {
+        // Arrange
+        let builder = ServiceBuilder::new()
+            .with_type::<UnitService>()
+            .with_health_check::<UnitService>()
+            .in_profile("test", ServiceBuilder::with_health_check::<UnitService>);
+
+        // Act
+        let services = builder.build_for(&["test"]);
+
+        // Assert
+        let report = services.check_health().await;
+        assert_eq!(report.entries.len(), 1);
+    }
+
+    #[test]
+    fn build_for_applies_profiles_in_registration_order() {
+        // Arrange
+        let builder = builder();
+
+        // Act
+        let services = builder.build_for(&["local", "test"]);
+
+        // Assert
+        assert_eq!(services.expect::<Config>().port, 2);
+    }
+
+    #[test]
+    fn build_from_env_without_variable_activates_no_profiles() {
+        // Arrange
+        let builder = builder();
+
+        // Act
+        let services = builder.build_from_env("STUDIOLE_DI_TEST_UNSET_PROFILE");
+
+        // Assert
+        assert_eq!(services.expect::<Config>().port, 8080);
+    }
+
+    #[test]
+    fn descriptors_report_contributing_profile() {
+        // Arrange
+        let services = builder()
+            .with_type_transient::<Database>()
+            .build_for(&["test"]);
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let profile_of = |name: &str| {
+            descriptors
+                .iter()
+                .find(|descriptor| descriptor.type_name.ends_with(name))
+                .map(|descriptor| (descriptor.lifetime, descriptor.profile.clone()))
+        };
+        assert_eq!(
+            profile_of("::Config"),
+            Some((ServiceLifetime::Instance, Some("test".to_owned())))
+        );
+        assert_eq!(
+            profile_of("::UnitService"),
+            Some((ServiceLifetime::Singleton, Some("test".to_owned())))
+        );
+        assert_eq!(
+            profile_of("::Database"),
+            Some((ServiceLifetime::Transient, None))
+        );
+    }
+}
@@ -15,71 +15,181 @@ impl ServiceProvider {
         let type_name = type_name::<T>();
         trace!(type_name, "Resolving service");
         let type_id = TypeId::of::<T>();
+        self.check_lifecycle(type_id, type_name)?;
+        let dynamic = self.resolve(type_id, type_name)?;
+        Ok(dynamic.expect_downcast::<T>())
+    }
 
+    /// Resolve a concrete type without the strict lifecycle checks.
+    ///
+    /// Used by init and deinit steps, which run before the provider is ready or
+    /// after shutdown has begun.
+    pub(crate) fn get_unguarded<T: Send + Sync + 'static>(
+        &self,
+    ) -> Result<Arc<T>, Report<ResolveError>> {
+        let dynamic = self.resolve(TypeId::of::<T>(), type_name::<T>())?;
+        Ok(dynamic.expect_downcast::<T>())
+    }
+
+    /// Resolve a type-erased instance by type.
+    pub(crate) fn resolve(&self, type_id: TypeId, type_name: &'static str) -> FactoryResult {
         if let Some(dynamic) = self.get_cached(type_id) {
-            return Ok(dynamic.expect_downcast::<T>());
+            return Ok(dynamic);
+        }
+        let Some(registration) = self.get_registration(type_id, type_name)? else {
+            return self.parent(type_name)?.resolve(type_id, type_name);
+        };
+        let context = LifetimeContext::new(self, type_id, registration);
+        if let Some(dynamic) = registration.lifetime.get(&context) {
+            return Ok(dynamic);
         }
-
-        let registration = self.get_registration(type_id, type_name)?;
         #[cfg(feature = "async")]
         if registration.is_async {
             return Err(Report::new(ResolveError::Async)).attach("type", type_name);
         }
         let dynamic = (registration.factory)(self)?;
-        self.cache_if_singleton(type_id, registration.scope, &dynamic);
-        Ok(dynamic.expect_downcast::<T>())
+        Ok(registration.lifetime.store(&context, dynamic))
     }
 
-    /// Look up a cached instance by type.
+    /// Look up a pre-built or cached singleton instance by type.
     pub(crate) fn get_cached(&self, type_id: TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
-        let instances = self
+        self.registry
+            .instances
+            .lock()
+            .expect("should be able to lock instances")
+            .get(&type_id)
+            .cloned()
+    }
+
+    /// Remove cached singleton instances so they are constructed afresh on next
+    /// resolution.
+    ///
+    /// Pre-built instances registered via
+    /// [`with_instance`](ServiceBuilder::with_instance) are kept.
+    pub(crate) fn evict(&self, type_ids: &[TypeId]) {
+        let mut instances = self
             .registry
             .instances
             .lock()
             .expect("should be able to lock instances");
-        instances.get(&type_id).map(Arc::clone)
+        for type_id in type_ids {
+            if !self.registry.instance_registrations.contains_key(type_id) {
+                instances.remove(type_id);
+            }
+        }
     }
 
     /// Look up a registration by type.
+    ///
+    /// Returns `None` if the type is not registered here but may be registered by
+    /// the parent provider.
     pub(crate) fn get_registration(
         &self,
         type_id: TypeId,
         type_name: &'static str,
-    ) -> Result<&Registration, Report<ResolveError>> {
+    ) -> Result<Option<&Registration>, Report<ResolveError>> {
+        match self.registry.factories.get(&type_id) {
+            Some(registration) => Ok(Some(registration)),
+            None if self.registry.parent.is_some() => Ok(None),
+            None => Err(Report::new(ResolveError::NotFound)).attach("type", type_name),
+        }
+    }
+
+    /// Get the parent provider to fall back to for unregistered types.
+    pub(crate) fn parent(
+        &self,
+        type_name: &'static str,
+    ) -> Result<&ServiceProvider, Report<ResolveError>> {
         self.registry
-            .factories
-            .get(&type_id)
+            .parent
+            .as_ref()
             .ok_or_else(|| Report::new(ResolveError::NotFound))
             .attach("type", type_name)
     }
 
     /// Run all registered init closures.
     ///
-    /// Returns [`InitError::AlreadyInitialized`] if called more than once.
+    /// - Sync singletons marked as eager are constructed before the init closures run
+    /// - Pools are filled to the minimum set by `PoolConfig::with_min`
+    /// - Steps run in registration order unless reordered by
+    ///   [`with_init_after`](ServiceBuilder::with_init_after) or
+    ///   [`with_init_before`](ServiceBuilder::with_init_before)
+    /// - Failures are handled according to the [`InitMode`]
+    ///
+    /// - Returns [`InitError::AlreadyInitialized`] if the [`Lifecycle`] is not
+    ///   [`Built`](Lifecycle::Built)
+    /// - Returns [`InitError::Cycle`] if the ordering constraints form a cycle
+    /// - Returns [`InitError::Aggregate`] if any step failed in [`InitMode::Rollback`]
+    /// - Returns `InitError::Async` if a step was registered with `with_init_async`
     pub fn init(self) -> Result<Self, Report<InitError>> {
-        if self.registry.initialized.swap(true, Ordering::SeqCst) {
-            return Err(Report::new(InitError::AlreadyInitialized));
+        self.begin_init()?;
+        let result = self.run_init();
+        self.finish_init(result).map(|()| self)
+    }
+
+    /// Sort and run the init steps.
+    fn run_init(&self) -> Result<(), Report<InitError>> {
+        let plan = sort_init_steps(&self.registry.inits, &self.registry.init_constraints)?;
+        self.construct_eager()?;
+        let steps = &self.registry.inits;
+        if self.registry.init_mode == InitMode::FailFast {
+            for step in plan.order().filter_map(|index| steps.get(index)) {
+                self.run_init_step_sync(step)?;
+            }
+            return Ok(());
         }
-        for init_fn in &self.registry.inits {
-            init_fn(&self)?;
+        let mut progress = InitProgress::new(&plan);
+        for index in plan.order() {
+            let Some(step) = steps.get(index) else {
+                continue;
+            };
+            if progress.is_runnable(index) {
+                progress.record(index, self.run_init_step_sync(step));
+            }
         }
-        Ok(self)
+        progress.finish(self, steps)
     }
 
-    /// Cache an instance if the registration is a singleton.
-    pub(crate) fn cache_if_singleton(
-        &self,
-        type_id: TypeId,
-        scope: Scope,
-        dynamic: &Arc<dyn Any + Send + Sync>,
-    ) {
-        if scope == Scope::Singleton {
-            self.registry
-                .instances
-                .lock()
-                .expect("should be able to lock instances")
-                .insert(type_id, Arc::clone(dynamic));
+    /// Run a single init step synchronously.
+    fn run_init_step_sync(&self, step: &InitStep) -> Result<(), Report<InitError>> {
+        match &step.run {
+            InitRun::Sync(init_fn) => init_fn(self)?,
+            #[cfg(feature = "async")]
+            InitRun::Async(_) => {
+                return Err(Report::new(InitError::Async)).attach("type", step.type_name);
+            }
         }
+        self.mark_initialized(step.type_id);
+        Ok(())
+    }
+
+    /// Construct every sync singleton marked as eager, in registration order.
+    ///
+    /// Dependencies are constructed first as each factory resolves them.
+    /// Async registrations are skipped as they can only be constructed by `warm_up`.
+    pub(crate) fn construct_eager(&self) -> Result<(), Report<InitError>> {
+        for (type_id, registration) in self.eager_registrations() {
+            #[cfg(feature = "async")]
+            if registration.is_async {
+                continue;
+            }
+            self.resolve(type_id, registration.type_name)
+                .change_context(InitError::Init)?;
+        }
+        #[cfg(feature = "pool")]
+        self.fill_pools().change_context(InitError::Init)?;
+        Ok(())
+    }
+
+    /// Get the registrations marked as eager, in registration order.
+    pub(crate) fn eager_registrations(&self) -> impl Iterator<Item = (TypeId, &Registration)> {
+        let factories = &self.registry.factories;
+        self.registry.order.iter().filter_map(|type_id| {
+            factories
+                .get(type_id)
+                .filter(|registration| registration.eager)
+                .map(|registration| (*type_id, registration))
+        })
     }
 }
 
@@ -96,11 +206,36 @@ pub enum ResolveError {
     #[cfg(feature = "async")]
     #[error("Service requires async resolution")]
     Async,
+    /// The service has an init step that has not completed.
+    ///
+    /// Only returned with [`ServiceBuilder::with_strict_lifecycle`].
+    #[error("Service is not initialized while the provider is {0}")]
+    NotReady(Lifecycle),
+    /// The provider has begun shutting down.
+    ///
+    /// Only returned with [`ServiceBuilder::with_strict_lifecycle`].
+    #[error("Service cannot be resolved while the provider is {0}")]
+    ShutDown(Lifecycle),
+    /// A [`Factory`] was requested for a service that is not registered as a transient.
+    #[error("Service is not registered as transient")]
+    NotTransient,
+    /// The provider was dropped before a [`Lazy`] or [`Factory`] handle was used.
+    #[error("Provider has been dropped")]
+    Dropped,
+    /// No pooled instance became available.
+    #[cfg(feature = "pool")]
+    #[error("Pool has no available instance")]
+    PoolExhausted,
+    /// A configuration section could not be loaded, bound or validated.
+    #[cfg(feature = "config")]
+    #[error("Failed to bind configuration")]
+    Config,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::AtomicUsize;
 
     #[test]
     fn singleton_shares_state() {
@@ -132,6 +267,45 @@ mod tests {
         assert_eq!(second.get("key"), None);
     }
 
+    #[test]
+    fn weak_shares_state_while_held() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_weak::<MemoryCache>()
+            .build();
+
+        // Act
+        let first = services.expect::<MemoryCache>();
+        first.set("key", "hello");
+        let second = services.expect::<MemoryCache>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(second.get("key"), Some(String::from("hello")));
+    }
+
+    #[test]
+    fn weak_rebuilds_after_release() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_weak::<CountedService>()
+            .build();
+        let counter = services.expect::<ConstructionCounter>();
+        let first = services.expect::<CountedService>();
+        let second = services.expect::<CountedService>();
+        let held = counter.get();
+
+        // Act
+        drop(first);
+        drop(second);
+        let _third = services.expect::<CountedService>();
+
+        // Assert
+        assert_eq!(held, 1);
+        assert_eq!(counter.get(), 2);
+    }
+
     #[test]
     fn unregistered_type_returns_not_found() {
         // Arrange
@@ -253,6 +427,199 @@ mod tests {
         assert_eq!(*calls, vec!["A".to_owned(), "B".to_owned()]);
     }
 
+    #[test]
+    fn service_provider_init_after() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(InitOrder {
+                calls: Mutex::new(Vec::new()),
+            })
+            .with_init_after::<OrderedInitA, OrderedInitB>()
+            .with_type::<OrderedInitA>()
+            .with_init::<OrderedInitA>()
+            .with_type::<OrderedInitB>()
+            .with_init::<OrderedInitB>()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let order = services.expect::<InitOrder>();
+        let calls = order.calls.lock().expect("should lock");
+        assert_eq!(*calls, vec!["B".to_owned(), "A".to_owned()]);
+    }
+
+    #[test]
+    fn service_provider_init_before() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(InitOrder {
+                calls: Mutex::new(Vec::new()),
+            })
+            .with_type::<OrderedInitA>()
+            .with_init::<OrderedInitA>()
+            .with_type::<OrderedInitB>()
+            .with_init::<OrderedInitB>()
+            .with_init_before::<OrderedInitB, OrderedInitA>()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let order = services.expect::<InitOrder>();
+        let calls = order.calls.lock().expect("should lock");
+        assert_eq!(*calls, vec!["B".to_owned(), "A".to_owned()]);
+    }
+
+    #[test]
+    fn service_provider_init_cycle() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(InitOrder {
+                calls: Mutex::new(Vec::new()),
+            })
+            .with_type::<OrderedInitA>()
+            .with_init::<OrderedInitA>()
+            .with_type::<OrderedInitB>()
+            .with_init::<OrderedInitB>()
+            .with_init_after::<OrderedInitA, OrderedInitB>()
+            .with_init_after::<OrderedInitB, OrderedInitA>()
+            .build();
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Cycle);
+    }
+
+    #[test]
+    fn service_provider_init_fail_fast_cannot_retry() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .build();
+        let _ = services.clone().init();
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::AlreadyInitialized);
+    }
+
+    #[test]
+    fn service_provider_init_rollback() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_type::<RollbackTracker>()
+            .with_init::<RollbackTracker>()
+            .with_deinit::<RollbackTracker>()
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .build();
+        let tracker = services.expect::<RollbackTracker>();
+        // Act
+        let output = services.clone().init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Aggregate);
+        assert_eq!(tracker.inits.load(Ordering::SeqCst), 1);
+        assert_eq!(tracker.deinits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn service_provider_init_rollback_evicts_rolled_back_instances() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_type::<RollbackTracker>()
+            .with_init::<RollbackTracker>()
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .build();
+        let first = services.expect::<RollbackTracker>();
+        let _ = services.clone().init();
+        let second = services.expect::<RollbackTracker>();
+        // Act
+        let output = services.clone().init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::Aggregate);
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(first.inits.load(Ordering::SeqCst), 1);
+        assert_eq!(second.inits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn service_provider_init_rollback_keeps_prebuilt_instances() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_instance(RollbackTracker::default())
+            .with_init::<RollbackTracker>()
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .build();
+        let first = services.expect::<RollbackTracker>();
+        // Act
+        let _ = services.clone().init();
+        // Assert
+        let second = services.expect::<RollbackTracker>();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn service_provider_init_rollback_keeps_first_failure_as_source() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .build();
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        let source = error.source().expect("should have source");
+        assert_eq!(source.to_string(), InitError::Init.to_string());
+    }
+
+    #[test]
+    fn service_provider_init_rollback_collects_failures() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .with_init::<InitTracker>()
+            .build();
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        let display = error.to_string();
+        assert!(display.contains(type_name::<FailingInit>()));
+        assert!(display.contains(type_name::<InitTracker>()));
+    }
+
+    #[test]
+    fn service_provider_init_rollback_skips_dependents() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_type::<FailingInit>()
+            .with_init::<FailingInit>()
+            .with_type::<InitTracker>()
+            .with_init::<InitTracker>()
+            .with_init_after::<InitTracker, FailingInit>()
+            .build();
+        // Act
+        let output = services.clone().init();
+        // Assert
+        assert!(output.is_err());
+        let tracker = services.expect::<InitTracker>();
+        assert!(!tracker.initialized.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn mixed_default_fields_resolve() {
         // Arrange
@@ -269,6 +636,99 @@ mod tests {
         assert_eq!(svc.port, 0);
     }
 
+    #[test]
+    fn eager_singleton_constructed_during_init() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_eager::<CountedService>()
+            .build();
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 0);
+        // Act
+        let services = services.expect_init();
+        // Assert
+        assert_eq!(counter.get(), 1);
+        let _service = services.expect::<CountedService>();
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    fn eager_singletons_constructs_all_singletons_during_init() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type::<CountedService>()
+            .with_eager_singletons()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    fn eager_singletons_skips_transients() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionCounter::default())
+            .with_type_transient::<CountedService>()
+            .with_eager_singletons()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let counter = services.expect::<ConstructionCounter>();
+        assert_eq!(counter.get(), 0);
+    }
+
+    #[test]
+    fn eager_singletons_constructed_in_registration_order() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(ConstructionOrder::default())
+            .with_type_eager::<Ordered<3>>()
+            .with_type_eager::<Ordered<1>>()
+            .with_type_eager::<Ordered<2>>()
+            .with_type_eager::<Ordered<5>>()
+            .with_type_eager::<Ordered<4>>()
+            .build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let order = services.expect::<ConstructionOrder>();
+        assert_eq!(*order.order.lock().expect("should lock"), [3, 1, 2, 5, 4]);
+    }
+
+    #[test]
+    fn eager_singleton_failure_fails_init() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_eager::<CountedService>()
+            .build();
+        // Act
+        let output = services.init();
+        // Assert
+        assert!(output.is_err());
+    }
+
+    #[derive(Default)]
+    struct ConstructionOrder {
+        order: Mutex<Vec<usize>>,
+    }
+
+    struct Ordered<const N: usize>;
+
+    impl<const N: usize> FromServices for Ordered<N> {
+        type Error = ResolveError;
+        fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            let order = services.get::<ConstructionOrder>()?;
+            order.order.lock().expect("should lock").push(N);
+            Ok(Self)
+        }
+    }
+
     struct InitTracker {
         initialized: AtomicBool,
     }
@@ -289,6 +749,33 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct RollbackTracker {
+        inits: AtomicUsize,
+        deinits: AtomicUsize,
+    }
+
+    impl FromServices for RollbackTracker {
+        type Error = ResolveError;
+        fn from_services(_services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            Ok(Self::default())
+        }
+    }
+
+    impl Init for RollbackTracker {
+        fn init(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.inits.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    impl Deinit for RollbackTracker {
+        fn deinit(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.deinits.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
     struct FailingInit;
 
     impl FromServices for FailingInit {
@@ -0,0 +1,142 @@
+//! Hierarchical child providers.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Create a child provider that falls back to this provider.
+    ///
+    /// - `configure` receives an empty [`ServiceBuilder`] and returns it with the
+    ///   child's registrations
+    /// - The child has its own registrations and singleton cache, and this
+    ///   provider is not modified
+    /// - Types the child does not register are resolved by this provider, so
+    ///   singletons owned by this provider stay shared between its children
+    /// - The child has its own [`Lifecycle`], so it must be initialized separately
+    /// - Types resolved through this provider are also subject to its strict
+    ///   lifecycle checks, if enabled
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tenant = services.child(|builder| builder.with_instance(TenantConfig::new("acme")));
+    /// ```
+    #[must_use]
+    pub fn child(
+        &self,
+        configure: impl FnOnce(ServiceBuilder) -> ServiceBuilder,
+    ) -> ServiceProvider {
+        let mut builder = configure(ServiceBuilder::new());
+        builder.parent = Some(self.clone());
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type::<UnitService>()
+            .build()
+    }
+
+    #[test]
+    fn child_falls_back_to_parent() {
+        // Arrange
+        let services = base();
+
+        // Act
+        let child = services.child(ServiceBuilder::with_type::<Database>);
+
+        // Assert
+        assert_eq!(child.expect::<Database>().config.port, 8080);
+        assert!(services.get::<Database>().is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn child_falls_back_to_parent_async() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 9090 })
+            .with_type_async::<AsyncDatabase>()
+            .build();
+
+        // Act
+        let child = services.child(|builder| builder);
+
+        // Assert
+        let db = child.expect_async::<AsyncDatabase>().await;
+        assert_eq!(db.config.port, 9090);
+    }
+
+    #[test]
+    fn child_shares_parent_singletons() {
+        // Arrange
+        let services = base();
+        let first = services.child(|builder| builder);
+        let second = services.child(|builder| builder);
+
+        // Act
+        let from_first = first.expect::<UnitService>();
+        let from_second = second.expect::<UnitService>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&from_first, &from_second));
+        assert!(Arc::ptr_eq(&from_first, &services.expect::<UnitService>()));
+    }
+
+    #[test]
+    fn child_registrations_shadow_parent() {
+        // Arrange
+        let services = base();
+
+        // Act
+        let child = services.child(|builder| {
+            builder
+                .with_instance(Config { port: 1 })
+                .with_type::<Database>()
+        });
+
+        // Assert
+        assert_eq!(child.expect::<Database>().config.port, 1);
+        assert_eq!(services.expect::<Config>().port, 8080);
+    }
+
+    #[test]
+    fn child_reports_not_found_when_no_level_registers_type() {
+        // Arrange
+        let child = base().child(|builder| builder);
+
+        // Act
+        let result = child.get::<Database>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn descriptors_report_serving_level() {
+        // Arrange
+        let child = base()
+            .child(|builder| builder.with_instance(Config { port: 1 }))
+            .child(ServiceBuilder::with_type::<Database>);
+
+        // Act
+        let descriptors = child.descriptors();
+
+        // Assert
+        let level_of = |name: &str| {
+            descriptors
+                .iter()
+                .find(|descriptor| descriptor.type_name.ends_with(name))
+                .map(|descriptor| descriptor.level)
+        };
+        assert_eq!(descriptors.len(), 3);
+        assert_eq!(level_of("::Database"), Some(0));
+        assert_eq!(level_of("::Config"), Some(1));
+        assert_eq!(level_of("::UnitService"), Some(2));
+    }
+}
@@ -0,0 +1,113 @@
+//! Registration introspection.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Describe every registered service, sorted by type name.
+    ///
+    /// Includes the services inherited from parent providers that are not
+    /// registered by this provider.
+    #[must_use]
+    pub fn descriptors(&self) -> Vec<ServiceDescriptor> {
+        let registry = &self.registry;
+        let instances =
+            registry
+                .instance_registrations
+                .values()
+                .map(|instance| ServiceDescriptor {
+                    type_name: instance.type_name,
+                    lifetime: ServiceLifetime::Instance,
+                    is_async: false,
+                    eager: false,
+                    profile: instance.profile.clone(),
+                    branch: None,
+                    level: 0,
+                    #[cfg(feature = "pool")]
+                    pool: None,
+                });
+        let factories =
+            registry
+                .factories
+                .iter()
+                .map(|(type_id, registration)| ServiceDescriptor {
+                    type_name: registration.type_name,
+                    lifetime: registration.lifetime.describe(),
+                    is_async: registration.is_async,
+                    eager: registration.eager,
+                    profile: registration.profile.clone(),
+                    branch: self.current_branch(*type_id),
+                    level: 0,
+                    #[cfg(feature = "pool")]
+                    pool: None,
+                });
+        let keyed = registry
+            .keyed
+            .values()
+            .map(|registration| ServiceDescriptor {
+                type_name: registration.type_name,
+                lifetime: ServiceLifetime::Keyed,
+                is_async: false,
+                eager: false,
+                profile: registration.profile.clone(),
+                branch: None,
+                level: 0,
+                #[cfg(feature = "pool")]
+                pool: None,
+            });
+        let mut descriptors: Vec<ServiceDescriptor> =
+            instances.chain(factories).chain(keyed).collect();
+        #[cfg(feature = "pool")]
+        descriptors.extend(registry.pooled.iter().map(|(type_id, registration)| {
+            ServiceDescriptor {
+                type_name: registration.type_name,
+                lifetime: ServiceLifetime::Pooled,
+                is_async: false,
+                eager: false,
+                profile: registration.profile.clone(),
+                branch: None,
+                level: 0,
+                pool: self.pool_stats_of(*type_id),
+            }
+        }));
+        if let Some(parent) = &registry.parent {
+            let inherited: Vec<ServiceDescriptor> = parent
+                .descriptors()
+                .into_iter()
+                .filter(|inherited| {
+                    !descriptors
+                        .iter()
+                        .any(|descriptor| descriptor.type_name == inherited.type_name)
+                })
+                .map(|inherited| ServiceDescriptor {
+                    level: inherited.level + 1,
+                    ..inherited
+                })
+                .collect();
+            descriptors.extend(inherited);
+        }
+        descriptors.sort_by_key(|descriptor| descriptor.type_name);
+        descriptors
+    }
+
+    /// Get the current branch of a trait switch.
+    #[cfg(feature = "traits")]
+    fn current_branch(&self, type_id: TypeId) -> Option<&'static str> {
+        let branch = self.registry.switches.get(&type_id)?;
+        branch(self)
+    }
+
+    /// Get the current branch of a trait switch.
+    #[cfg(not(feature = "traits"))]
+    #[expect(
+        clippy::unused_self,
+        reason = "matches the signature with the traits feature"
+    )]
+    fn current_branch(&self, _type_id: TypeId) -> Option<&'static str> {
+        None
+    }
+
+    /// Get the profiles that were active when the provider was built.
+    #[must_use]
+    pub fn profiles(&self) -> &[String] {
+        &self.registry.profiles
+    }
+}
@@ -0,0 +1,412 @@
+//! Provider lifecycle transitions.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Get the current [`Lifecycle`] state.
+    #[must_use]
+    pub fn lifecycle(&self) -> Lifecycle {
+        *self.lock_lifecycle()
+    }
+
+    /// Shut down the provider.
+    ///
+    /// - Runs the [`Deinit`] hook of every completed init step in reverse order
+    /// - Drops every cached instance, disposing keyed and pooled instances registered
+    ///   with [`with_dispose`](ServiceBuilder::with_dispose)
+    /// - Keeps pre-built instances registered via
+    ///   [`with_instance`](ServiceBuilder::with_instance)
+    /// - Calls [`Lifetime::shutdown`] for every registration
+    /// - Instances of thread-local registrations cached by other threads are dropped
+    ///   when those threads next resolve them or exit
+    /// - Calling more than once has no effect
+    ///
+    /// Returns [`InitError::Aggregate`] if any [`Deinit`] hook failed, after the
+    /// remaining hooks have run.
+    pub fn shutdown(&self) -> Result<(), Report<InitError>> {
+        {
+            let mut lifecycle = self.lock_lifecycle();
+            if matches!(*lifecycle, Lifecycle::ShuttingDown | Lifecycle::Disposed) {
+                return Ok(());
+            }
+            *lifecycle = Lifecycle::ShuttingDown;
+        }
+        let initialized: Vec<TypeId> = self.lock_initialized().drain(..).rev().collect();
+        let mut report: Option<Report<InitError>> = None;
+        for type_id in initialized {
+            let Some(deinit_fn) = self.registry.deinits.get(&type_id) else {
+                continue;
+            };
+            if let Err(error) = deinit_fn(self) {
+                let type_name = self
+                    .registry
+                    .inits
+                    .iter()
+                    .find(|step| step.type_id == type_id)
+                    .map_or("unknown", |step| step.type_name);
+                let failure = format!("{type_name}: {error:?}");
+                let aggregate = report.unwrap_or_else(|| Report::new(InitError::Aggregate));
+                report = Some(aggregate.attach("deinit failed", failure));
+            }
+        }
+        self.registry
+            .instances
+            .lock()
+            .expect("should be able to lock instances")
+            .retain(|type_id, _| self.registry.instance_registrations.contains_key(type_id));
+        self.registry
+            .weak_instances
+            .lock()
+            .expect("should be able to lock weak instances")
+            .clear();
+        self.clear_thread_local();
+        for (type_id, registration) in self.registry.factories.iter() {
+            let context = LifetimeContext::new(self, *type_id, registration);
+            registration.lifetime.shutdown(&context);
+        }
+        #[cfg(feature = "ttl")]
+        self.registry
+            .ttl_instances
+            .lock()
+            .expect("should be able to lock ttl instances")
+            .clear();
+        self.dispose_keyed();
+        #[cfg(feature = "pool")]
+        self.close_pools();
+        *self.lock_lifecycle() = Lifecycle::Disposed;
+        report.map_or(Ok(()), Err)
+    }
+
+    /// Move from [`Lifecycle::Built`] to [`Lifecycle::Initializing`].
+    pub(crate) fn begin_init(&self) -> Result<(), Report<InitError>> {
+        let mut lifecycle = self.lock_lifecycle();
+        if *lifecycle != Lifecycle::Built {
+            return Err(Report::new(InitError::AlreadyInitialized)).attach("lifecycle", *lifecycle);
+        }
+        *lifecycle = Lifecycle::Initializing;
+        Ok(())
+    }
+
+    /// Move to the lifecycle state that follows an init run.
+    ///
+    /// - A successful run moves to [`Lifecycle::Ready`]
+    /// - A failed run in [`InitMode::Rollback`] moves back to [`Lifecycle::Built`] so
+    ///   init can be retried
+    /// - A failed run in [`InitMode::FailFast`] stays in [`Lifecycle::Initializing`]
+    pub(crate) fn finish_init(
+        &self,
+        result: Result<(), Report<InitError>>,
+    ) -> Result<(), Report<InitError>> {
+        let mut lifecycle = self.lock_lifecycle();
+        if result.is_ok() {
+            *lifecycle = Lifecycle::Ready;
+        } else if self.registry.init_mode == InitMode::Rollback {
+            self.lock_initialized().clear();
+            *lifecycle = Lifecycle::Built;
+        }
+        result
+    }
+
+    /// Record that the init step of a type completed.
+    pub(crate) fn mark_initialized(&self, type_id: TypeId) {
+        self.lock_initialized().push(type_id);
+    }
+
+    /// Reject resolution in lifecycle states where it is unsafe.
+    ///
+    /// - Has no effect unless [`ServiceBuilder::with_strict_lifecycle`] was called
+    /// - Types not registered here are also checked against the lifecycle of the
+    ///   parent provider that serves them
+    pub(crate) fn check_lifecycle(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<(), Report<ResolveError>> {
+        if self.registry.strict {
+            self.check_own_lifecycle(type_id, type_name)?;
+        }
+        match &self.registry.parent {
+            Some(parent) if !self.is_registered(type_id) => {
+                parent.check_lifecycle(type_id, type_name)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject resolution if this provider's lifecycle makes it unsafe.
+    fn check_own_lifecycle(
+        &self,
+        type_id: TypeId,
+        type_name: &'static str,
+    ) -> Result<(), Report<ResolveError>> {
+        let lifecycle = self.lifecycle();
+        match lifecycle {
+            Lifecycle::Ready => Ok(()),
+            Lifecycle::ShuttingDown | Lifecycle::Disposed => {
+                Err(Report::new(ResolveError::ShutDown(lifecycle))).attach("type", type_name)
+            }
+            Lifecycle::Built | Lifecycle::Initializing => {
+                if self.registry.init_types.contains(&type_id)
+                    && !self.lock_initialized().contains(&type_id)
+                {
+                    return Err(Report::new(ResolveError::NotReady(lifecycle)))
+                        .attach("type", type_name);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Check whether a type is registered here rather than by the parent provider.
+    fn is_registered(&self, type_id: TypeId) -> bool {
+        let registry = &self.registry;
+        let registered = registry.factories.contains_key(&type_id)
+            || registry.instance_registrations.contains_key(&type_id)
+            || registry.keyed.contains_key(&type_id);
+        #[cfg(feature = "pool")]
+        let registered = registered || registry.pooled.contains_key(&type_id);
+        registered
+    }
+
+    /// Lock the lifecycle state.
+    fn lock_lifecycle(&self) -> MutexGuard<'_, Lifecycle> {
+        self.registry
+            .lifecycle
+            .lock()
+            .expect("should be able to lock lifecycle")
+    }
+
+    /// Lock the types whose init step completed.
+    fn lock_initialized(&self) -> MutexGuard<'_, Vec<TypeId>> {
+        self.registry
+            .initialized
+            .lock()
+            .expect("should be able to lock initialized")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifecycle_transitions() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+        assert_eq!(services.lifecycle(), Lifecycle::Built);
+        // Act
+        let services = services.expect_init();
+        let ready = services.lifecycle();
+        services.shutdown().expect("should shut down");
+        // Assert
+        assert_eq!(ready, Lifecycle::Ready);
+        assert_eq!(services.lifecycle(), Lifecycle::Disposed);
+    }
+
+    #[test]
+    fn lifecycle_rollback_returns_to_built() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_init_mode(InitMode::Rollback)
+            .with_init::<StepA>()
+            .build();
+        // Act
+        let output = services.clone().init();
+        // Assert
+        assert!(output.is_err());
+        assert_eq!(services.lifecycle(), Lifecycle::Built);
+    }
+
+    #[test]
+    fn init_after_shutdown_returns_error() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+        services.shutdown().expect("should shut down");
+        // Act
+        let output = services.init();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(error.current_context(), &InitError::AlreadyInitialized);
+    }
+
+    #[test]
+    fn strict_rejects_get_before_init() {
+        // Arrange
+        let services = steps_builder().with_strict_lifecycle().build();
+        // Act
+        let output = services.get::<StepA>();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(
+            error.current_context(),
+            &ResolveError::NotReady(Lifecycle::Built)
+        );
+    }
+
+    #[test]
+    fn strict_allows_get_without_init_step_before_init() {
+        // Arrange
+        let services = steps_builder().with_strict_lifecycle().build();
+        // Act
+        let output = services.get::<Calls>();
+        // Assert
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn strict_allows_get_after_init() {
+        // Arrange
+        let services = steps_builder().with_strict_lifecycle().build();
+        // Act
+        let services = services.expect_init();
+        // Assert
+        let calls = services.expect::<Calls>();
+        assert_eq!(*calls.inits.lock().expect("should lock"), vec!["A", "B"]);
+        assert!(services.get::<StepB>().is_ok());
+    }
+
+    #[test]
+    fn strict_rejects_get_after_shutdown() {
+        // Arrange
+        let services = steps_builder()
+            .with_strict_lifecycle()
+            .build()
+            .expect_init();
+        // Act
+        services.shutdown().expect("should shut down");
+        let output = services.get::<Calls>();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(
+            error.current_context(),
+            &ResolveError::ShutDown(Lifecycle::Disposed)
+        );
+    }
+
+    #[test]
+    fn strict_parent_rejects_child_get_before_parent_init() {
+        // Arrange
+        let parent = steps_builder().with_strict_lifecycle().build();
+        let child = parent.child(ServiceBuilder::with_type::<UnitService>);
+        // Act
+        let output = child.get::<StepA>();
+        // Assert
+        let error = output.err().expect("should fail");
+        assert_eq!(
+            error.current_context(),
+            &ResolveError::NotReady(Lifecycle::Built)
+        );
+    }
+
+    #[test]
+    fn strict_parent_allows_child_get_of_own_registration() {
+        // Arrange
+        let parent = steps_builder().with_strict_lifecycle().build();
+        let child = parent.child(ServiceBuilder::with_type::<UnitService>);
+        // Act
+        let output = child.get::<UnitService>();
+        // Assert
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn shutdown_runs_deinit_in_reverse_order() {
+        // Arrange
+        let services = steps_builder().build().expect_init();
+        let calls = services.expect::<Calls>();
+        // Act
+        services.shutdown().expect("should shut down");
+        // Assert
+        assert_eq!(*calls.deinits.lock().expect("should lock"), vec!["B", "A"]);
+    }
+
+    #[test]
+    fn shutdown_drops_cached_instances() {
+        // Arrange
+        let services = steps_builder().build().expect_init();
+        let before = services.expect::<StepA>();
+        // Act
+        services.shutdown().expect("should shut down");
+        let after = services.expect::<StepA>();
+        // Assert
+        assert!(!Arc::ptr_eq(&before, &after));
+    }
+
+    #[test]
+    fn shutdown_keeps_prebuilt_instances() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build()
+            .expect_init();
+        let before = services.expect::<Config>();
+        // Act
+        services.shutdown().expect("should shut down");
+        let after = services.get::<Config>().expect("should resolve");
+        // Assert
+        assert!(Arc::ptr_eq(&before, &after));
+    }
+
+    fn steps_builder() -> ServiceBuilder {
+        ServiceBuilder::new()
+            .with_type::<Calls>()
+            .with_type::<StepA>()
+            .with_init::<StepA>()
+            .with_deinit::<StepA>()
+            .with_type::<StepB>()
+            .with_init::<StepB>()
+            .with_deinit::<StepB>()
+    }
+
+    #[derive(Default)]
+    struct Calls {
+        inits: Mutex<Vec<&'static str>>,
+        deinits: Mutex<Vec<&'static str>>,
+    }
+
+    impl FromServices for Calls {
+        type Error = ResolveError;
+        fn from_services(_services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            Ok(Self::default())
+        }
+    }
+
+    #[derive(FromServices)]
+    struct StepA {
+        calls: Arc<Calls>,
+    }
+
+    impl Init for StepA {
+        fn init(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.calls.inits.lock().expect("should lock").push("A");
+            Ok(())
+        }
+    }
+
+    impl Deinit for StepA {
+        fn deinit(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.calls.deinits.lock().expect("should lock").push("A");
+            Ok(())
+        }
+    }
+
+    /// Depends on [`StepA`] so its construction is rejected until `StepA` is initialized.
+    #[derive(FromServices)]
+    struct StepB {
+        calls: Arc<Calls>,
+        _a: Arc<StepA>,
+    }
+
+    impl Init for StepB {
+        fn init(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.calls.inits.lock().expect("should lock").push("B");
+            Ok(())
+        }
+    }
+
+    impl Deinit for StepB {
+        fn deinit(&self, _services: &ServiceProvider) -> Result<(), Report<InitError>> {
+            self.calls.deinits.lock().expect("should lock").push("B");
+            Ok(())
+        }
+    }
+}
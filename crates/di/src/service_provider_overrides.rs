@@ -0,0 +1,188 @@
+//! Providers derived from an existing provider's registrations.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Create a provider that replaces some registrations of this provider.
+    ///
+    /// - `configure` receives an empty [`ServiceBuilder`] and returns it with the
+    ///   replacement registrations
+    /// - Registrations that are not replaced are shared with this provider
+    /// - The new provider has its own singleton cache and starts in
+    ///   [`Lifecycle::Built`], so it must be initialized separately
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let services = production_services();
+    /// let test_services = services.with_overrides(|builder| builder.with_instance(FakeClock::new()));
+    /// ```
+    #[must_use]
+    pub fn with_overrides(
+        &self,
+        configure: impl FnOnce(ServiceBuilder) -> ServiceBuilder,
+    ) -> ServiceProvider {
+        let overrides = configure(ServiceBuilder::new());
+        let active: Vec<&str> = self.registry.profiles.iter().map(String::as_str).collect();
+        ServiceBuilder::from_registry(&self.registry)
+            .merge(overrides, None, &active)
+            .build_for(&active)
+    }
+
+    /// Create a provider with the same registrations and an isolated singleton cache.
+    ///
+    /// - The registrations are shared rather than copied, so forking is cheap
+    /// - The cache is seeded with the instances registered via
+    ///   [`with_instance`](ServiceBuilder::with_instance), which are shared with this
+    ///   provider
+    /// - Every other singleton is constructed afresh on first resolution
+    /// - The fork starts in [`Lifecycle::Built`], so it must be initialized separately
+    #[must_use]
+    pub fn fork(&self) -> ServiceProvider {
+        ServiceProvider {
+            registry: Arc::new(self.registry.fork()),
+        }
+    }
+}
+
+impl ServiceBuilder {
+    /// Create a builder holding the registrations of an existing registry.
+    pub(crate) fn from_registry(registry: &ServiceRegistry) -> Self {
+        Self {
+            factories: registry.factories.as_ref().clone(),
+            order: registry.order.as_ref().clone(),
+            instances: registry.instance_registrations.clone(),
+            keyed: registry.keyed.clone(),
+            #[cfg(feature = "pool")]
+            pooled: registry.pooled.clone(),
+            inits: registry.inits.clone(),
+            init_constraints: registry.init_constraints.clone(),
+            deinits: registry.deinits.clone(),
+            disposers: registry.disposers.clone(),
+            #[cfg(feature = "pool")]
+            resetters: registry.resetters.clone(),
+            init_mode: registry.init_mode,
+            strict: registry.strict,
+            parent: registry.parent.clone(),
+            #[cfg(feature = "config")]
+            config: registry.config.clone(),
+            #[cfg(feature = "traits")]
+            switches: registry.switches.clone(),
+            eager: false,
+            #[cfg(feature = "health")]
+            health_checks: registry.health_checks.clone(),
+            #[cfg(feature = "health")]
+            health_check_timeout: registry.health_check_timeout,
+            #[cfg(feature = "hosting")]
+            hosted: registry.hosted.clone(),
+            #[cfg(feature = "jobs")]
+            jobs: registry.jobs.clone(),
+            profiles: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn production() -> ServiceProvider {
+        ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type::<Database>()
+            .with_type::<UnitService>()
+            .build()
+    }
+
+    #[test]
+    fn with_overrides_replaces_registration() {
+        // Arrange
+        let services = production();
+
+        // Act
+        let overridden =
+            services.with_overrides(|builder| builder.with_instance(Config { port: 1 }));
+
+        // Assert
+        assert_eq!(overridden.expect::<Database>().config.port, 1);
+        assert_eq!(services.expect::<Database>().config.port, 8080);
+    }
+
+    #[test]
+    fn with_overrides_has_own_singleton_cache() {
+        // Arrange
+        let services = production();
+        let original = services.expect::<UnitService>();
+
+        // Act
+        let overridden = services.with_overrides(|builder| builder);
+        let first = overridden.expect::<UnitService>();
+        let second = overridden.expect::<UnitService>();
+
+        // Assert
+        assert!(!Arc::ptr_eq(&original, &first));
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn with_overrides_replaces_factory_with_instance() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type::<CountedService>()
+            .with_instance(ConstructionCounter::default())
+            .build();
+
+        // Act
+        let overridden = services.with_overrides(|builder| builder.with_instance(CountedService));
+        let _service = overridden.expect::<CountedService>();
+
+        // Assert
+        assert_eq!(overridden.expect::<ConstructionCounter>().get(), 0);
+    }
+
+    #[test]
+    fn fork_has_isolated_singleton_cache() {
+        // Arrange
+        let services = production();
+        let original = services.expect::<UnitService>();
+
+        // Act
+        let fork = services.fork();
+        let forked = fork.expect::<UnitService>();
+
+        // Assert
+        assert!(!Arc::ptr_eq(&original, &forked));
+        assert!(Arc::ptr_eq(&forked, &fork.expect::<UnitService>()));
+    }
+
+    #[test]
+    fn fork_shares_registrations_and_instances() {
+        // Arrange
+        let services = production();
+
+        // Act
+        let fork = services.fork();
+
+        // Assert
+        assert!(Arc::ptr_eq(
+            &services.registry.factories,
+            &fork.registry.factories
+        ));
+        assert!(Arc::ptr_eq(
+            &services.expect::<Config>(),
+            &fork.expect::<Config>()
+        ));
+    }
+
+    #[test]
+    fn fork_starts_built() {
+        // Arrange
+        let services = production().init().expect("should init");
+
+        // Act
+        let fork = services.fork();
+
+        // Assert
+        assert_eq!(services.lifecycle(), Lifecycle::Ready);
+        assert_eq!(fork.lifecycle(), Lifecycle::Built);
+    }
+}
@@ -0,0 +1,120 @@
+//! Construction with runtime arguments.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Construct a type from caller-supplied arguments and resolved dependencies.
+    ///
+    /// The type does not need to be registered. A new instance is constructed on
+    /// every call and is not cached.
+    ///
+    /// ```ignore
+    /// let session = services.get_with::<Session, _>((user_id,))?;
+    /// ```
+    pub fn get_with<T: FromServicesWith<Args>, Args>(
+        &self,
+        args: Args,
+    ) -> Result<T, Report<ResolveError>> {
+        let type_name = type_name::<T>();
+        trace!(type_name, "Constructing service with arguments");
+        self.check_lifecycle(TypeId::of::<T>(), type_name)?;
+        T::from_services_with(self, args)
+            .change_context(ResolveError::Factory)
+            .attach("type", type_name)
+    }
+
+    /// Construct a type asynchronously from caller-supplied arguments and resolved
+    /// dependencies.
+    ///
+    /// The type does not need to be registered. A new instance is constructed on
+    /// every call and is not cached.
+    #[cfg(feature = "async")]
+    pub async fn get_with_async<T: FromServicesWithAsync<Args>, Args>(
+        &self,
+        args: Args,
+    ) -> Result<T, Report<ResolveError>> {
+        let type_name = type_name::<T>();
+        trace!(type_name, "Constructing service with arguments async");
+        self.check_lifecycle(TypeId::of::<T>(), type_name)?;
+        T::from_services_with_async(self, args)
+            .await
+            .change_context(ResolveError::Factory)
+            .attach("type", type_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_with_args() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+
+        // Act
+        let session = services
+            .get_with::<DerivedSession, _>((42, String::from("/tmp")))
+            .expect("should construct");
+
+        // Assert
+        assert_eq!(session.config.port, 8080);
+        assert_eq!(session.user_id, 42);
+        assert_eq!(session.path, "/tmp");
+        assert_eq!(session.retries, 0);
+    }
+
+    #[test]
+    fn get_with_constructs_every_call() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .build();
+
+        // Act
+        let first = services
+            .get_with::<DerivedSession, _>((1, String::new()))
+            .expect("should construct");
+        let second = services
+            .get_with::<DerivedSession, _>((2, String::new()))
+            .expect("should construct");
+
+        // Assert
+        assert_eq!(first.user_id, 1);
+        assert_eq!(second.user_id, 2);
+    }
+
+    #[test]
+    fn get_with_missing_dependency_returns_factory() {
+        // Arrange
+        let services = ServiceBuilder::new().build();
+
+        // Act
+        let result = services.get_with::<DerivedSession, _>((42, String::new()));
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::Factory);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn get_with_async_args() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(Config { port: 8080 })
+            .with_type_async::<AsyncDatabase>()
+            .build();
+
+        // Act
+        let session = services
+            .get_with_async::<DerivedAsyncSession, _>((42,))
+            .await
+            .expect("should construct");
+
+        // Assert
+        assert_eq!(session.db.config.port, 8080);
+        assert_eq!(session.user_id, 42);
+    }
+}
@@ -0,0 +1,54 @@
+//! Runtime feature flags.
+use crate::prelude::*;
+
+/// Named on/off flags that can change while the provider is running.
+///
+/// Read by the selector of a [`TraitSwitch`] on every resolution.
+#[derive(Debug, Default)]
+pub struct FeatureFlags {
+    flags: RwLock<HashMap<String, bool>>,
+}
+
+impl FeatureFlags {
+    /// Create a [`FeatureFlags`] with every flag disabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the initial state of a flag.
+    #[must_use]
+    pub fn with_flag(self, name: &str, enabled: bool) -> Self {
+        self.set(name, enabled);
+        self
+    }
+
+    /// Enable or disable a flag.
+    pub fn set(&self, name: &str, enabled: bool) {
+        self.flags
+            .write()
+            .expect("should be able to write flags")
+            .insert(name.to_owned(), enabled);
+    }
+
+    /// Check whether a flag is enabled.
+    ///
+    /// Flags that have never been set are disabled.
+    #[must_use]
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.flags
+            .read()
+            .expect("should be able to read flags")
+            .get(name)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl FromServices for FeatureFlags {
+    type Error = ResolveError;
+
+    fn from_services(_services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        Ok(Self::default())
+    }
+}
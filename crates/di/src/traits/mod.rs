@@ -1,6 +1,15 @@
 //! Trait object registration and resolution.
 
+#[cfg(feature = "traits")]
+mod feature_flags;
 #[cfg(feature = "traits")]
 mod service_builder_with_trait;
 #[cfg(feature = "traits")]
 mod service_provider_get_trait;
+#[cfg(feature = "traits")]
+mod trait_switch;
+
+#[cfg(feature = "traits")]
+pub use feature_flags::*;
+#[cfg(feature = "traits")]
+pub use trait_switch::*;
@@ -7,7 +7,7 @@ impl ServiceBuilder {
     /// - Automatically registers `Impl` via [`with_type`](ServiceBuilder::with_type)
     ///   if it has not already been registered
     /// - If `Impl` was already registered (by a prior `with_trait` or `with_type`
-    ///   call), the existing registration and its scope are kept unchanged
+    ///   call), the existing registration and its lifetime are kept unchanged
     ///
     /// # Example
     ///
@@ -22,7 +22,7 @@ impl ServiceBuilder {
     where
         Impl: FromServices + Unsize<Trait>,
     {
-        self.register_trait::<Trait, Impl>(Scope::Singleton)
+        self.register_trait::<Trait, Impl>(SingletonLifetime)
     }
 
     /// Register a concrete type as transient and resolve it as a trait object.
@@ -30,37 +30,43 @@ impl ServiceBuilder {
     /// - Automatically registers `Impl` via [`with_type_transient`](ServiceBuilder::with_type_transient)
     ///   if it has not already been registered
     /// - If `Impl` was already registered (by a prior `with_trait_transient` or `with_type_transient`
-    ///   call), the existing registration and its scope are kept unchanged
+    ///   call), the existing registration and its lifetime are kept unchanged
     #[must_use]
     pub fn with_trait_transient<Trait: ?Sized + Send + Sync + 'static, Impl>(self) -> Self
     where
         Impl: FromServices + Unsize<Trait>,
     {
-        self.register_trait::<Trait, Impl>(Scope::Transient)
+        self.register_trait::<Trait, Impl>(TransientLifetime)
     }
 
-    /// Register a trait object with the given scope.
+    /// Register a trait object with the given lifetime.
     #[expect(
         clippy::as_conversions,
         reason = "unsizing coercion to resolve concrete type as trait object"
     )]
-    fn register_trait<Trait: ?Sized + Send + Sync + 'static, Impl>(mut self, scope: Scope) -> Self
+    fn register_trait<Trait: ?Sized + Send + Sync + 'static, Impl>(
+        mut self,
+        lifetime: impl Lifetime + Clone,
+    ) -> Self
     where
         Impl: FromServices + Unsize<Trait>,
     {
         if !self.factories.contains_key(&TypeId::of::<Impl>()) {
-            self = self.register_type::<Impl>(scope);
+            self = self.register_type::<Impl>(lifetime.clone());
         }
         let type_id = TypeId::of::<Arc<Trait>>();
-        let factory: SyncFactory = Box::new(|services: &ServiceProvider| {
+        let factory: SyncFactory = Arc::new(|services: &ServiceProvider| {
             let instance = services.get::<Impl>()? as Arc<Trait>;
             Ok(instance.into_any())
         });
-        self.factories.insert(
+        self.insert_factory(
             type_id,
             Registration {
-                scope,
+                type_name: type_name::<Arc<Trait>>(),
+                lifetime: Arc::new(lifetime),
+                eager: false,
                 is_async: false,
+                profile: None,
                 factory,
                 async_factory: None,
             },
@@ -9,18 +9,8 @@ impl ServiceProvider {
         let type_name = type_name::<Arc<Trait>>();
         trace!(type_name, "Resolving trait service");
         let type_id = TypeId::of::<Arc<Trait>>();
-
-        if let Some(dynamic) = self.get_cached(type_id) {
-            return Ok(Arc::clone(&dynamic.expect_downcast::<Arc<Trait>>()));
-        }
-
-        let registration = self.get_registration(type_id, type_name)?;
-        #[cfg(feature = "async")]
-        if registration.is_async {
-            return Err(Report::new(ResolveError::Async)).attach("type", type_name);
-        }
-        let dynamic = (registration.factory)(self)?;
-        self.cache_if_singleton(type_id, registration.scope, &dynamic);
+        self.check_lifecycle(type_id, type_name)?;
+        let dynamic = self.resolve(type_id, type_name)?;
         Ok(Arc::clone(&dynamic.expect_downcast::<Arc<Trait>>()))
     }
 }
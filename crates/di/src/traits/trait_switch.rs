@@ -0,0 +1,328 @@
+//! Runtime selection between trait implementations.
+use crate::prelude::*;
+
+/// Closure that chooses a branch from the current [`FeatureFlags`].
+type Selector = Box<dyn Fn(&FeatureFlags) -> &'static str + Send + Sync>;
+
+/// Closure that reports the current branch of a switch, used for introspection.
+pub(crate) type BranchFn = Arc<dyn Fn(&ServiceProvider) -> Option<&'static str> + Send + Sync>;
+
+/// Candidate implementations of a trait and the selector that chooses between them.
+///
+/// Registered via [`ServiceBuilder::with_trait_switch`].
+pub struct TraitSwitch<Trait: ?Sized + Send + Sync + 'static> {
+    selector: Selector,
+    candidates: Vec<Candidate<Trait>>,
+}
+
+/// Implementation of a trait that is used when its branch is selected.
+struct Candidate<Trait: ?Sized + Send + Sync + 'static> {
+    branch: &'static str,
+    register: fn(ServiceBuilder) -> ServiceBuilder,
+    resolve: fn(&ServiceProvider) -> Result<Arc<Trait>, Report<ResolveError>>,
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static> TraitSwitch<Trait> {
+    /// Create a [`TraitSwitch`] that chooses a branch with `selector`.
+    #[must_use]
+    pub fn new(selector: impl Fn(&FeatureFlags) -> &'static str + Send + Sync + 'static) -> Self {
+        Self {
+            selector: Box::new(selector),
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Use `Impl` when the selector returns `branch`.
+    ///
+    /// `Impl` is registered as a singleton via [`with_type`](ServiceBuilder::with_type)
+    /// if it has not already been registered.
+    #[must_use]
+    pub fn with_candidate<Impl>(mut self, branch: &'static str) -> Self
+    where
+        Impl: FromServices + Unsize<Trait>,
+    {
+        self.candidates.push(Candidate {
+            branch,
+            register: register_candidate::<Impl>,
+            resolve: resolve_candidate::<Trait, Impl>,
+        });
+        self
+    }
+
+    /// Evaluate the selector against the current [`FeatureFlags`].
+    fn select(&self, services: &ServiceProvider) -> Result<&'static str, Report<ResolveError>> {
+        let flags = services.get::<FeatureFlags>()?;
+        Ok((self.selector)(&flags))
+    }
+
+    /// Resolve the candidate for the current branch.
+    fn resolve(&self, services: &ServiceProvider) -> Result<Arc<Trait>, Report<ResolveError>> {
+        let branch = self.select(services)?;
+        let Some(candidate) = self
+            .candidates
+            .iter()
+            .find(|candidate| candidate.branch == branch)
+        else {
+            return Err(Report::new(ResolveError::NotFound))
+                .attach("type", type_name::<Arc<Trait>>())
+                .attach("branch", branch);
+        };
+        trace!(
+            type_name = type_name::<Arc<Trait>>(),
+            branch, "Selected branch"
+        );
+        (candidate.resolve)(services)
+    }
+}
+
+impl ServiceBuilder {
+    /// Register a trait object whose implementation is chosen on every resolution.
+    ///
+    /// - The selector is evaluated against the [`FeatureFlags`] service each time
+    ///   the trait is resolved, so the binding is transient
+    /// - [`FeatureFlags`] is registered as a singleton if it has not already been
+    ///   registered
+    /// - [`ServiceProvider::descriptors`] reports the current branch
+    ///
+    /// Use [`with_trait_switch_lifetime`](ServiceBuilder::with_trait_switch_lifetime)
+    /// to cache the selected implementation instead.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ServiceBuilder::new()
+    ///     .with_trait_switch::<dyn Search>(
+    ///         TraitSwitch::new(|flags| if flags.is_enabled("new-search") { "new" } else { "legacy" })
+    ///             .with_candidate::<NewSearch>("new")
+    ///             .with_candidate::<LegacySearch>("legacy"),
+    ///     )
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn with_trait_switch<Trait: ?Sized + Send + Sync + 'static>(
+        self,
+        switch: TraitSwitch<Trait>,
+    ) -> Self {
+        self.with_trait_switch_lifetime(switch, TransientLifetime)
+    }
+
+    /// Register a trait object whose implementation is chosen when it is not cached
+    /// by a custom [`Lifetime`].
+    ///
+    /// The selector is only evaluated when `lifetime` has no cached instance, so
+    /// changes to the [`FeatureFlags`] take effect once the cached instance is
+    /// released. [`ServiceProvider::descriptors`] reports the branch the selector
+    /// currently chooses, which may differ from the cached implementation.
+    #[must_use]
+    pub fn with_trait_switch_lifetime<Trait: ?Sized + Send + Sync + 'static>(
+        mut self,
+        switch: TraitSwitch<Trait>,
+        lifetime: impl Lifetime,
+    ) -> Self {
+        for candidate in &switch.candidates {
+            self = (candidate.register)(self);
+        }
+        let flags_id = TypeId::of::<FeatureFlags>();
+        if !self.factories.contains_key(&flags_id) && !self.instances.contains_key(&flags_id) {
+            self = self.with_type::<FeatureFlags>();
+        }
+        let switch = Arc::new(switch);
+        let type_id = TypeId::of::<Arc<Trait>>();
+        let resolver = switch.clone();
+        let factory: SyncFactory = Arc::new(move |services: &ServiceProvider| {
+            let instance = resolver.resolve(services)?;
+            Ok(instance.into_any())
+        });
+        let branch: BranchFn = Arc::new(move |services| switch.select(services).ok());
+        self.switches.insert(type_id, branch);
+        self.insert_factory(
+            type_id,
+            Registration {
+                type_name: type_name::<Arc<Trait>>(),
+                lifetime: Arc::new(lifetime),
+                eager: false,
+                is_async: false,
+                profile: None,
+                factory,
+                async_factory: None,
+            },
+        );
+        self
+    }
+}
+
+/// Register a candidate implementation if it has not already been registered.
+fn register_candidate<Impl: FromServices>(builder: ServiceBuilder) -> ServiceBuilder {
+    if builder.factories.contains_key(&TypeId::of::<Impl>()) {
+        return builder;
+    }
+    builder.with_type::<Impl>()
+}
+
+/// Resolve a candidate implementation as the trait object.
+#[expect(
+    clippy::as_conversions,
+    reason = "unsizing coercion to resolve concrete type as trait object"
+)]
+fn resolve_candidate<Trait: ?Sized + Send + Sync + 'static, Impl>(
+    services: &ServiceProvider,
+) -> Result<Arc<Trait>, Report<ResolveError>>
+where
+    Impl: FromServices + Unsize<Trait>,
+{
+    Ok(services.get::<Impl>()? as Arc<Trait>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    trait Search: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    #[derive(FromServices)]
+    struct NewSearch;
+
+    impl Search for NewSearch {
+        fn name(&self) -> &'static str {
+            "new"
+        }
+    }
+
+    #[derive(FromServices)]
+    struct LegacySearch;
+
+    impl Search for LegacySearch {
+        fn name(&self) -> &'static str {
+            "legacy"
+        }
+    }
+
+    fn search_switch() -> TraitSwitch<dyn Search> {
+        TraitSwitch::new(|flags| {
+            if flags.is_enabled("new-search") {
+                "new"
+            } else {
+                "legacy"
+            }
+        })
+        .with_candidate::<NewSearch>("new")
+        .with_candidate::<LegacySearch>("legacy")
+    }
+
+    #[test]
+    fn with_trait_switch_selects_on_every_resolution() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_trait_switch::<dyn Search>(search_switch())
+            .build();
+        let flags = services.expect::<FeatureFlags>();
+
+        // Act
+        let before = services.expect_trait::<dyn Search>();
+        flags.set("new-search", true);
+        let after = services.expect_trait::<dyn Search>();
+
+        // Assert
+        assert_eq!(before.name(), "legacy");
+        assert_eq!(after.name(), "new");
+    }
+
+    /// Lifetime caching one instance until it is cleared.
+    #[derive(Clone, Default)]
+    struct Pinned(Arc<Mutex<Option<Arc<dyn Any + Send + Sync>>>>);
+
+    impl Lifetime for Pinned {
+        fn get(&self, _context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+            self.0.lock().expect("should lock").clone()
+        }
+
+        fn store(
+            &self,
+            _context: &LifetimeContext<'_>,
+            instance: Arc<dyn Any + Send + Sync>,
+        ) -> Arc<dyn Any + Send + Sync> {
+            Arc::clone(self.0.lock().expect("should lock").get_or_insert(instance))
+        }
+    }
+
+    #[test]
+    fn with_trait_switch_lifetime_caches_selected_implementation() {
+        // Arrange
+        let pinned = Pinned::default();
+        let services = ServiceBuilder::new()
+            .with_trait_switch_lifetime::<dyn Search>(search_switch(), pinned.clone())
+            .build();
+        let flags = services.expect::<FeatureFlags>();
+
+        // Act
+        let before = services.expect_trait::<dyn Search>();
+        flags.set("new-search", true);
+        let cached = services.expect_trait::<dyn Search>();
+        pinned.0.lock().expect("should lock").take();
+        let after = services.expect_trait::<dyn Search>();
+
+        // Assert
+        assert_eq!(before.name(), "legacy");
+        assert_eq!(cached.name(), "legacy");
+        assert_eq!(after.name(), "new");
+    }
+
+    #[test]
+    fn with_trait_switch_uses_registered_flags() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(FeatureFlags::new().with_flag("new-search", true))
+            .with_trait_switch::<dyn Search>(search_switch())
+            .build();
+
+        // Act
+        let search = services.expect_trait::<dyn Search>();
+
+        // Assert
+        assert_eq!(search.name(), "new");
+    }
+
+    #[test]
+    fn with_trait_switch_reports_unknown_branch() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_trait_switch::<dyn Search>(
+                TraitSwitch::new(|_| "missing").with_candidate::<NewSearch>("new"),
+            )
+            .build();
+
+        // Act
+        let result = services.get_trait::<dyn Search>();
+
+        // Assert
+        let report = result.err().expect("should fail");
+        assert_eq!(report.current_context(), &ResolveError::NotFound);
+    }
+
+    #[test]
+    fn descriptors_report_current_branch() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_trait_switch::<dyn Search>(search_switch())
+            .build();
+        let flags = services.expect::<FeatureFlags>();
+        let branch = || {
+            services
+                .descriptors()
+                .into_iter()
+                .find(|descriptor| descriptor.type_name == type_name::<Arc<dyn Search>>())
+                .and_then(|descriptor| descriptor.branch)
+        };
+
+        // Act
+        let before = branch();
+        flags.set("new-search", true);
+        let after = branch();
+
+        // Assert
+        assert_eq!(before, Some("legacy"));
+        assert_eq!(after, Some("new"));
+    }
+}
@@ -0,0 +1,6 @@
+//! Time-to-live services.
+mod service_builder_ttl;
+mod service_provider_ttl;
+mod ttl;
+
+pub use ttl::*;
@@ -0,0 +1,26 @@
+//! Time-to-live service registration.
+use crate::prelude::*;
+
+impl ServiceBuilder {
+    /// Register a type that is cached until it expires, then constructed afresh.
+    ///
+    /// ```ignore
+    /// builder
+    ///     .with_type_ttl::<Credentials>(Duration::from_mins(5))
+    ///     .with_type_ttl::<Token>(Ttl::new(Duration::from_hours(1)).with_refresh_ahead(Duration::from_mins(5)))
+    /// ```
+    #[must_use]
+    pub fn with_type_ttl<T: FromServices>(self, ttl: impl Into<Ttl>) -> Self {
+        self.register_type::<T>(ttl.into())
+    }
+
+    /// Register a type for async resolution that is cached until it expires, then
+    /// constructed afresh.
+    ///
+    /// Refresh-ahead runs on the current Tokio runtime. Without one the instance is
+    /// rebuilt by the first resolution after expiry.
+    #[must_use]
+    pub fn with_type_async_ttl<T: FromServicesAsync>(self, ttl: impl Into<Ttl>) -> Self {
+        self.register_type_async::<T>(ttl.into())
+    }
+}
@@ -0,0 +1,368 @@
+//! Time-to-live service caching.
+use crate::prelude::*;
+
+impl ServiceProvider {
+    /// Get the error of the last failed background refresh of a time-to-live type.
+    ///
+    /// Returns `None` if `T` is not cached, or if no refresh has failed since the
+    /// cached instance was constructed.
+    #[must_use]
+    pub fn ttl_refresh_error<T: 'static>(&self) -> Option<Arc<Report<ResolveError>>> {
+        self.lock_ttl_instances()
+            .get(&TypeId::of::<T>())
+            .and_then(|entry| entry.refresh_error.clone())
+    }
+
+    /// Get the cached instance of a time-to-live registration if it has not expired.
+    ///
+    /// Starts a background refresh if one is due.
+    pub(crate) fn get_ttl(
+        &self,
+        type_id: TypeId,
+        registration: &Registration,
+        ttl: Ttl,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        let now = Instant::now();
+        let mut instances = self.lock_ttl_instances();
+        let entry = instances.get_mut(&type_id)?;
+        if now >= entry.expires_at {
+            trace!(type_name = registration.type_name, "TTL service expired");
+            return None;
+        }
+        let value = entry.value.clone();
+        if !entry.refreshing && ttl.is_refresh_due(entry.expires_at, now) {
+            entry.refreshing = true;
+            drop(instances);
+            self.spawn_ttl_refresh(type_id, registration.clone(), ttl);
+        }
+        Some(value)
+    }
+
+    /// Cache an instance of a time-to-live registration.
+    pub(crate) fn store_ttl(&self, type_id: TypeId, ttl: Ttl, value: Arc<dyn Any + Send + Sync>) {
+        self.lock_ttl_instances()
+            .insert(type_id, TtlEntry::new(value, ttl));
+    }
+
+    /// Rebuild an instance in the background.
+    ///
+    /// - Sync registrations are rebuilt on the blocking pool of the current Tokio
+    ///   runtime, or on a new thread if there is no runtime
+    /// - Async registrations are rebuilt on the current Tokio runtime
+    fn spawn_ttl_refresh(&self, type_id: TypeId, registration: Registration, ttl: Ttl) {
+        trace!(type_name = registration.type_name, "Refreshing TTL service");
+        let services = self.clone();
+        let runtime = RuntimeHandle::try_current().ok();
+        if !registration.is_async {
+            let refresh = move || {
+                let result = (registration.factory)(&services);
+                services.finish_ttl_refresh(type_id, &registration, ttl, result);
+            };
+            match runtime {
+                Some(runtime) => drop(runtime.spawn_blocking(refresh)),
+                None => drop(thread::spawn(refresh)),
+            }
+            return;
+        }
+        let Some(runtime) = runtime else {
+            self.cancel_ttl_refresh(type_id);
+            return;
+        };
+        runtime.spawn(async move {
+            let result = services.call_factory(&registration).await;
+            services.finish_ttl_refresh(type_id, &registration, ttl, result);
+        });
+    }
+
+    /// Cache a refreshed instance, or keep the stale instance if the refresh failed.
+    ///
+    /// - A failure is logged and recorded for [`ttl_refresh_error`](Self::ttl_refresh_error)
+    /// - The result is discarded if shutdown has begun, so the cleared cache is not
+    ///   repopulated
+    fn finish_ttl_refresh(
+        &self,
+        type_id: TypeId,
+        registration: &Registration,
+        ttl: Ttl,
+        result: FactoryResult,
+    ) {
+        let mut instances = self.lock_ttl_instances();
+        if matches!(
+            self.lifecycle(),
+            Lifecycle::ShuttingDown | Lifecycle::Disposed
+        ) {
+            trace!(
+                type_name = registration.type_name,
+                "Discarding TTL refresh after shutdown"
+            );
+            return;
+        }
+        match result {
+            Ok(value) => {
+                instances.insert(type_id, TtlEntry::new(value, ttl));
+            }
+            Err(error) => {
+                warn!(
+                    type_name = registration.type_name,
+                    "Failed to refresh TTL service, keeping stale instance: {error:?}"
+                );
+                if let Some(entry) = instances.get_mut(&type_id) {
+                    entry.refreshing = false;
+                    entry.refresh_error = Some(Arc::new(error));
+                }
+            }
+        }
+    }
+
+    /// Allow the next resolution to start another refresh.
+    fn cancel_ttl_refresh(&self, type_id: TypeId) {
+        if let Some(entry) = self.lock_ttl_instances().get_mut(&type_id) {
+            entry.refreshing = false;
+        }
+    }
+
+    /// Lock the instances of time-to-live registrations.
+    fn lock_ttl_instances(&self) -> MutexGuard<'_, HashMap<TypeId, TtlEntry>> {
+        self.registry
+            .ttl_instances
+            .lock()
+            .expect("should be able to lock ttl instances")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::thread::sleep;
+    use tokio::task::yield_now;
+    use tokio::time::sleep as sleep_async;
+
+    /// Token whose construction fails while [`FailSwitch`] is on.
+    struct Token {
+        generation: usize,
+    }
+
+    /// Toggle that makes [`Token`] construction fail.
+    #[derive(Default)]
+    struct FailSwitch {
+        fail: AtomicBool,
+        attempts: AtomicUsize,
+    }
+
+    impl FromServices for Token {
+        type Error = ResolveError;
+
+        fn from_services(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+            let switch = services.get::<FailSwitch>()?;
+            let generation = switch.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if switch.fail.load(Ordering::SeqCst) {
+                return Err(Report::new(ResolveError::Factory));
+            }
+            Ok(Self { generation })
+        }
+    }
+
+    impl FromServicesAsync for Token {
+        type Error = ResolveError;
+
+        async fn from_services_async(
+            services: &ServiceProvider,
+        ) -> Result<Self, Report<ResolveError>> {
+            Token::from_services(services)
+        }
+    }
+
+    /// Wait until `condition` holds or a second has passed.
+    fn wait_for(condition: impl Fn() -> bool) {
+        for _ in 0..100 {
+            if condition() {
+                return;
+            }
+            sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn cached_until_expired() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(Duration::from_millis(50))
+            .build();
+        let first = services.expect::<Token>();
+
+        // Act
+        let second = services.expect::<Token>();
+        sleep(Duration::from_millis(60));
+        let third = services.expect::<Token>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(third.generation, 2);
+    }
+
+    #[test]
+    fn expired_failure_returns_error() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(Duration::from_millis(20))
+            .build();
+        let _first = services.expect::<Token>();
+        services
+            .expect::<FailSwitch>()
+            .fail
+            .store(true, Ordering::SeqCst);
+
+        // Act
+        sleep(Duration::from_millis(30));
+        let result = services.get::<Token>();
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn refresh_ahead_serves_old_until_ready() {
+        // Arrange
+        let ttl = Ttl::new(Duration::from_secs(10)).with_refresh_ahead(Duration::from_secs(10));
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(ttl)
+            .build();
+        let switch = services.expect::<FailSwitch>();
+        let first = services.expect::<Token>();
+
+        // Act
+        let during = services.expect::<Token>();
+        wait_for(|| switch.attempts.load(Ordering::SeqCst) >= 2);
+        wait_for(|| !Arc::ptr_eq(&first, &services.expect::<Token>()));
+        let after = services.expect::<Token>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &during));
+        assert!(after.generation >= 2);
+    }
+
+    #[test]
+    fn refresh_failure_keeps_stale() {
+        // Arrange
+        let ttl = Ttl::new(Duration::from_secs(10)).with_refresh_ahead(Duration::from_secs(10));
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(ttl)
+            .build();
+        let switch = services.expect::<FailSwitch>();
+        let first = services.expect::<Token>();
+        switch.fail.store(true, Ordering::SeqCst);
+
+        // Act
+        let _trigger = services.expect::<Token>();
+        wait_for(|| switch.attempts.load(Ordering::SeqCst) >= 2);
+        wait_for(|| {
+            let instances = services.lock_ttl_instances();
+            instances.values().all(|entry| !entry.refreshing)
+        });
+        let stale = services.expect::<Token>();
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &stale));
+        let error = services
+            .ttl_refresh_error::<Token>()
+            .expect("should record refresh error");
+        assert_eq!(error.current_context(), &ResolveError::Factory);
+    }
+
+    #[test]
+    fn refresh_after_shutdown_is_discarded() {
+        // Arrange
+        let ttl = Ttl::new(Duration::from_secs(10));
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(ttl)
+            .build();
+        let registration = services
+            .registry
+            .factories
+            .get(&TypeId::of::<Token>())
+            .expect("should be registered")
+            .clone();
+        let _first = services.expect::<Token>();
+        services.shutdown().expect("should shut down");
+        let value = (registration.factory)(&services);
+
+        // Act
+        services.finish_ttl_refresh(TypeId::of::<Token>(), &registration, ttl, value);
+
+        // Assert
+        assert!(services.lock_ttl_instances().is_empty());
+    }
+
+    #[tokio::test]
+    async fn sync_refresh_ahead_on_blocking_pool() {
+        // Arrange
+        let ttl = Ttl::new(Duration::from_secs(10)).with_refresh_ahead(Duration::from_secs(10));
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_ttl::<Token>(ttl)
+            .build();
+        let first = services.expect::<Token>();
+
+        // Act
+        let during = services.expect::<Token>();
+        let mut after = during.clone();
+        for _ in 0..100 {
+            sleep_async(Duration::from_millis(10)).await;
+            after = services.expect::<Token>();
+            if after.generation == 2 {
+                break;
+            }
+        }
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &during));
+        assert_eq!(after.generation, 2);
+    }
+
+    #[tokio::test]
+    async fn async_refresh_ahead_on_runtime() {
+        // Arrange
+        let ttl = Ttl::new(Duration::from_secs(10)).with_refresh_ahead(Duration::from_secs(10));
+        let services = ServiceBuilder::new()
+            .with_instance(FailSwitch::default())
+            .with_type_async_ttl::<Token>(ttl)
+            .build();
+        let first = services.expect_async::<Token>().await;
+
+        // Act
+        let during = services.expect_async::<Token>().await;
+        let mut after = during.clone();
+        for _ in 0..100 {
+            yield_now().await;
+            after = services.expect_async::<Token>().await;
+            if after.generation == 2 {
+                break;
+            }
+        }
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &during));
+        assert_eq!(after.generation, 2);
+    }
+
+    #[test]
+    fn descriptors_report_ttl() {
+        // Arrange
+        let services = ServiceBuilder::new()
+            .with_type_ttl::<Token>(Duration::from_mins(1))
+            .build();
+
+        // Act
+        let descriptors = services.descriptors();
+
+        // Assert
+        let descriptor = descriptors.first().expect("should describe ttl service");
+        assert_eq!(descriptor.lifetime, ServiceLifetime::Ttl);
+    }
+}
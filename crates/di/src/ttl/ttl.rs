@@ -0,0 +1,99 @@
+//! Time-to-live configuration.
+use crate::prelude::*;
+
+/// How long a time-to-live service is cached.
+///
+/// Pass to [`ServiceBuilder::with_type_ttl`], or pass a [`Duration`] directly for
+/// a TTL without refresh-ahead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ttl {
+    duration: Duration,
+    refresh_ahead: Option<Duration>,
+}
+
+impl Ttl {
+    /// Cache for `duration` after construction.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            refresh_ahead: None,
+        }
+    }
+
+    /// Rebuild in the background once less than `lead` remains before expiry.
+    ///
+    /// The old instance is returned until the new one is ready. If the rebuild fails
+    /// the failure is logged and recorded for
+    /// [`ServiceProvider::ttl_refresh_error`], the old instance stays in use, and the
+    /// rebuild is retried by the next resolution.
+    #[must_use]
+    pub fn with_refresh_ahead(mut self, lead: Duration) -> Self {
+        self.refresh_ahead = Some(lead);
+        self
+    }
+
+    /// Get when an instance constructed at `now` expires.
+    pub(crate) fn expires_at(&self, now: Instant) -> Instant {
+        now + self.duration
+    }
+
+    /// Check if an instance expiring at `expires_at` is due for a refresh.
+    pub(crate) fn is_refresh_due(&self, expires_at: Instant, now: Instant) -> bool {
+        self.refresh_ahead
+            .is_some_and(|lead| now + lead >= expires_at)
+    }
+}
+
+impl From<Duration> for Ttl {
+    fn from(duration: Duration) -> Self {
+        Self::new(duration)
+    }
+}
+
+impl Lifetime for Ttl {
+    fn get(&self, context: &LifetimeContext<'_>) -> Option<Arc<dyn Any + Send + Sync>> {
+        context
+            .services()
+            .get_ttl(context.type_id(), context.registration(), *self)
+    }
+
+    fn store(
+        &self,
+        context: &LifetimeContext<'_>,
+        instance: Arc<dyn Any + Send + Sync>,
+    ) -> Arc<dyn Any + Send + Sync> {
+        context
+            .services()
+            .store_ttl(context.type_id(), *self, instance.clone());
+        instance
+    }
+
+    fn describe(&self) -> ServiceLifetime {
+        ServiceLifetime::Ttl
+    }
+}
+
+/// Cached instance of a time-to-live registration.
+pub(crate) struct TtlEntry {
+    /// Cached instance.
+    pub value: Arc<dyn Any + Send + Sync>,
+    /// When the instance expires.
+    pub expires_at: Instant,
+    /// Whether a background refresh is in progress.
+    pub refreshing: bool,
+    /// Error of the last failed background refresh of this instance.
+    pub refresh_error: Option<Arc<Report<ResolveError>>>,
+}
+
+impl TtlEntry {
+    /// Create an entry for an instance constructed now.
+    pub fn new(value: Arc<dyn Any + Send + Sync>, ttl: Ttl) -> Self {
+        Self {
+            value,
+            expires_at: ttl.expires_at(Instant::now()),
+            refreshing: false,
+            refresh_error: None,
+        }
+    }
+}